@@ -0,0 +1,118 @@
+use aoc_common::generate::streamed_input;
+use aoc_common::{blocks, Generator, ParseError, Rng, Solution, Stream, StreamError, Streaming};
+
+fn parse(rows: &Vec<String>) -> Result<Vec<i32>, ParseError> {
+    blocks(rows).iter()
+        .map(|block| block.iter().map(|row| row.parse::<i32>()).sum())
+        .collect()
+}
+
+fn part1(buckets: &Vec<i32>) -> i32 {
+    return *buckets.iter().max().expect("Ouch");
+}
+
+fn part2(buckets: &Vec<i32>) -> i32 {
+    let mut values = buckets.clone();
+    values.sort();
+    return values.iter().rev().take(3).sum();
+}
+
+/// Calls `add` with the calories of each elf while reading the input.
+fn stream_buckets(input: &mut Stream, mut add: impl FnMut(i32)) -> Result<(), StreamError> {
+    let last = input.try_fold_rows(None, |bucket: Option<i32>, row| {
+        if row.text.trim().is_empty() {
+            bucket.into_iter().for_each(&mut add);
+            Ok(None)
+        } else {
+            Ok(Some(bucket.unwrap_or(0) + row.parse::<i32>()?))
+        }
+    })?;
+    last.into_iter().for_each(add);
+    Ok(())
+}
+
+fn stream_part1(input: &mut Stream) -> Result<i32, StreamError> {
+    let mut max = None;
+    stream_buckets(input, |bucket| max = max.max(Some(bucket)))?;
+    Ok(max.expect("Ouch"))
+}
+
+fn stream_part2(input: &mut Stream) -> Result<i32, StreamError> {
+    let mut top = vec!();
+    stream_buckets(input, |bucket| {
+        top.push(bucket);
+        top.sort_by(|a, b| b.cmp(a));
+        top.truncate(3);
+    })?;
+    Ok(top.iter().sum())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<i32>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<i32>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i32>) -> i32 {
+        part2(input)
+    }
+}
+
+impl Streaming for Day01 {
+    fn stream_part1(input: &mut Stream) -> Result<i32, StreamError> {
+        stream_part1(input)
+    }
+
+    fn stream_part2(input: &mut Stream) -> Result<i32, StreamError> {
+        stream_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        for _ in 0..rng.range(1..=8) {
+            input.push_str(&format!("{}\n", rng.range(1000..=60000)));
+        }
+        input.push('\n');
+    }
+}
+
+impl Generator for Day01 {
+    // counted in elves
+    const SIZE: usize = 250;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day01>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day01>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day01>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day01>(Day01::SIZE, &[1, 2]);
+    }
+}
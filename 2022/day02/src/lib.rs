@@ -0,0 +1,156 @@
+use aoc_common::generate::streamed_input;
+use aoc_common::{lines, Generator, ParseError, Rng, Solution, Stream, StreamError, Streaming, Token};
+
+fn conv(a: &str) -> char {
+    match a {
+        "A" | "X" => 'R',
+        "B" | "Y" => 'P',
+        "C" | "Z" => 'S',
+        _ => panic!("Unknown input {a}")
+    }
+}
+
+fn round_winner(a: char, b: char) -> i32 {
+    if a == b {
+        return 0;
+    }
+    match (a, b) {
+        ('R', 'P') | ('P', 'S') | ('S', 'R') => 1,
+        _ => -1
+    }
+}
+
+fn shape_points(a: char) -> i32 {
+    match a {
+        'R' => 1,
+        'P' => 2,
+        'S' => 3,
+        _ => panic!("Unknown input {a}")
+    }
+}
+
+fn outcome_points(a: i32) -> i32 {
+    (a + 1) * 3
+}
+
+fn parse_round(line: Token) -> Result<(String, String), ParseError> {
+    let tokens = line.split(' ');
+    let (opp, second) = (tokens.get(0)?, tokens.get(1)?);
+    if !["A", "B", "C"].contains(&opp.text) {
+        return Err(opp.error("expected A, B or C"));
+    }
+    if !["X", "Y", "Z"].contains(&second.text) {
+        return Err(second.error("expected X, Y or Z"));
+    }
+    Ok((opp.text.to_string(), second.text.to_string()))
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<(String, String)>, ParseError> {
+    lines(rows).map(parse_round).collect()
+}
+
+fn score1(opp: &str, mine: &str) -> i32 {
+    let opp = conv(opp);
+    let mine = conv(mine);
+    let winner = round_winner(opp, mine);
+    shape_points(mine) + outcome_points(winner)
+}
+
+fn score2(opp: &str, outcome: &str) -> i32 {
+    let opp = conv(opp);
+    let expected = match outcome {
+        "X" => -1,
+        "Y" => 0,
+        "Z" => 1,
+        _ => panic!("Unknown input")
+    };
+
+    for needed in ['R', 'P', 'S'] {
+        if round_winner(opp, needed) == expected {
+            return shape_points(needed) + outcome_points(expected);
+        }
+    }
+    panic!("No shape gives the outcome {outcome}");
+}
+
+fn part1(rounds: &Vec<(String, String)>) -> i32 {
+    rounds.iter().map(|(opp, mine)| score1(opp, mine)).sum()
+}
+
+fn part2(rounds: &Vec<(String, String)>) -> i32 {
+    rounds.iter().map(|(opp, outcome)| score2(opp, outcome)).sum()
+}
+
+fn stream_score(input: &mut Stream, score: fn(&str, &str) -> i32) -> Result<i32, StreamError> {
+    input.try_fold_rows(0, |sum, row| {
+        let (opp, second) = parse_round(row)?;
+        Ok(sum + score(&opp, &second))
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(String, String)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<(String, String)>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<(String, String)>) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<(String, String)>) -> i32 {
+        part2(input)
+    }
+}
+
+impl Streaming for Day02 {
+    fn stream_part1(input: &mut Stream) -> Result<i32, StreamError> {
+        stream_score(input, score1)
+    }
+
+    fn stream_part2(input: &mut Stream) -> Result<i32, StreamError> {
+        stream_score(input, score2)
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        input.push_str(&format!("{} {}\n", rng.choose(&["A", "B", "C"]), rng.choose(&["X", "Y", "Z"])));
+    }
+}
+
+impl Generator for Day02 {
+    // counted in rounds
+    const SIZE: usize = 2500;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day02>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day02>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day02>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day02>(Day02::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::streamed_input;
+use aoc_common::{lines, Generator, ParseError, Rng, Solution, Stream, StreamError, Streaming, Token};
+
+fn priority(a: char) -> u32 {
+    if a.is_uppercase() {
+        a as u32 - 'A' as u32 + 27
+    } else {
+        a as u32 - 'a' as u32 + 1
+    }
+}
+
+// TODO How to do it without cloning the characters all the time?
+// TODO What's the proper way to implement variadic functions in Rust?
+fn intersect(first: &str, others: &[&str]) -> Vec<char> {
+    let mut set: HashSet<char> = HashSet::from_iter(first.chars());
+    for &other in others {
+        set = set.intersection(&HashSet::from_iter(other.chars()))
+            .copied()
+            .collect();
+    }
+    set.iter().copied().collect::<Vec<char>>()
+}
+
+fn parse_rucksack(line: Token) -> Result<String, ParseError> {
+    if let Some(item) = line.chars().find(|item| !item.text.bytes().all(|b| b.is_ascii_alphabetic())) {
+        return Err(item.error("expected an item from a-z or A-Z"));
+    }
+    if !line.text.len().is_multiple_of(2) {
+        return Err(line.error("expected an even number of items"));
+    }
+    Ok(line.text.to_string())
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    lines(rows).map(parse_rucksack).collect()
+}
+
+fn misplaced_priority(row: &str) -> u32 {
+    let left_right = row.split_at(row.len() / 2);
+    let common = intersect(left_right.0, &[left_right.1]);
+    assert_eq!(common.len(), 1);
+    priority(*common.first().expect("Ouch"))
+}
+
+fn badge_priority(first: &str, second: &str, third: &str) -> u32 {
+    let common = intersect(first, &[second, third]);
+    assert_eq!(common.len(), 1);
+    priority(*common.first().expect("Ouch"))
+}
+
+fn part1(rows: &Vec<String>) -> u32 {
+    rows.iter().map(|row| misplaced_priority(row)).sum()
+}
+
+fn part2(rows: &Vec<String>) -> u32 {
+    let mut sum: u32 = 0;
+    for group in rows.iter().step_by(3)
+        .zip(rows.iter().skip(1).step_by(3)
+            .zip(rows.iter().skip(2).step_by(3))) {
+        sum += badge_priority(group.0, group.1.0, group.1.1);
+    }
+    return sum;
+}
+
+fn stream_part1(input: &mut Stream) -> Result<u32, StreamError> {
+    input.try_fold_rows(0, |sum, row| Ok(sum + misplaced_priority(&parse_rucksack(row)?)))
+}
+
+fn stream_part2(input: &mut Stream) -> Result<u32, StreamError> {
+    // the first rucksacks of the current group
+    let mut group: Vec<String> = vec!();
+    input.try_fold_rows(0, |sum, row| {
+        let rucksack = parse_rucksack(row)?;
+        if group.len() < 2 {
+            group.push(rucksack);
+            return Ok(sum);
+        }
+        let badge = badge_priority(&group[0], &group[1], &rucksack);
+        group.clear();
+        Ok(sum + badge)
+    })
+}
+
+/// A group of three rucksacks: each one has a single item within both compartments, and the badge
+/// is the only item all of them have.
+fn generate_group(rng: &mut Rng, input: &mut String) {
+    let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+    rng.shuffle(&mut items);
+    let badge = items[0];
+    // disjoint items for the compartments of each rucksack
+    for (idx, pools) in items[4..].chunks(16).enumerate() {
+        let misplaced = items[1 + idx];
+        let len = rng.range(0..=6) as usize;
+        let mut left = [badge, misplaced].into_iter()
+            .chain((0..len).map(|_| *rng.choose(&pools[..8])))
+            .collect::<Vec<_>>();
+        let mut right = [misplaced].into_iter()
+            .chain((0..=len).map(|_| *rng.choose(&pools[8..])))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut left);
+        rng.shuffle(&mut right);
+        input.extend(left.into_iter().chain(right));
+        input.push('\n');
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<String>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<String>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>) -> u32 {
+        part2(input)
+    }
+}
+
+impl Streaming for Day03 {
+    fn stream_part1(input: &mut Stream) -> Result<u32, StreamError> {
+        stream_part1(input)
+    }
+
+    fn stream_part2(input: &mut Stream) -> Result<u32, StreamError> {
+        stream_part2(input)
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        generate_group(rng, input)
+    }
+}
+
+impl Generator for Day03 {
+    // counted in groups of three rucksacks
+    const SIZE: usize = 100;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day03>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day03>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day03>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day03>(Day03::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_common::generate::streamed_input;
+use aoc_common::{lines, Generator, IntervalSet, ParseError, Rng, Solution, Stream, StreamError, Streaming, Token};
+
+type Assignment = IntervalSet;
+
+fn parse_assignment(token: Token) -> Result<Assignment, ParseError> {
+    let (start, end) = token.split_once("-")?;
+    Ok(IntervalSet::from(start.parse()?..=end.parse()?))
+}
+
+fn parse_pair(line: Token) -> Result<(Assignment, Assignment), ParseError> {
+    let (left, right) = line.split_once(",")?;
+    Ok((parse_assignment(left)?, parse_assignment(right)?))
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    lines(rows).map(parse_pair).collect()
+}
+
+fn count<P>(pairs: &Vec<(Assignment, Assignment)>, predicate: P) -> u32
+    where P: Fn(&Assignment, &Assignment) -> bool
+{
+    let mut count: u32 = 0;
+    for (left, right) in pairs {
+        if predicate(left, right) {
+            count += 1
+        }
+    }
+    return count;
+}
+
+fn fully_contained(left: &Assignment, right: &Assignment) -> bool {
+    left.is_subset(right) || right.is_subset(left)
+}
+
+fn overlapping(left: &Assignment, right: &Assignment) -> bool {
+    !left.intersection(right).is_empty()
+}
+
+fn part1(pairs: &Vec<(Assignment, Assignment)>) -> u32 {
+    count(pairs, fully_contained)
+}
+
+fn part2(pairs: &Vec<(Assignment, Assignment)>) -> u32 {
+    count(pairs, overlapping)
+}
+
+fn stream_count(input: &mut Stream, predicate: fn(&Assignment, &Assignment) -> bool) -> Result<u32, StreamError> {
+    input.try_fold_rows(0, |count, row| {
+        let (left, right) = parse_pair(row)?;
+        Ok(count + predicate(&left, &right) as u32)
+    })
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<(Assignment, Assignment)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<(Assignment, Assignment)>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<(Assignment, Assignment)>) -> u32 {
+        part2(input)
+    }
+}
+
+impl Streaming for Day04 {
+    fn stream_part1(input: &mut Stream) -> Result<u32, StreamError> {
+        stream_count(input, fully_contained)
+    }
+
+    fn stream_part2(input: &mut Stream) -> Result<u32, StreamError> {
+        stream_count(input, overlapping)
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        let mut section = || {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        let ((a, b), (c, d)) = (section(), section());
+        input.push_str(&format!("{a}-{b},{c}-{d}\n"));
+    }
+}
+
+impl Generator for Day04 {
+    // counted in pairs
+    const SIZE: usize = 1000;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day04>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day04>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day04>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day04>(Day04::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,160 @@
+use aoc_common::{blocks, end_of_input, Generator, ParseError, Rng, Solution};
+
+pub struct Procedure {
+    num: usize,
+    from: usize,
+    to: usize,
+}
+
+fn parse(rows: &Vec<String>) -> Result<(Vec<Vec<char>>, Vec<Procedure>), ParseError> {
+    let blocks = blocks(rows);
+    let [stack_rows, procedure_rows] = blocks.as_slice() else {
+        return Err(end_of_input(rows).error("expected the stacks and the procedures separated by a blank line"));
+    };
+
+    // the last row only contains the numbers of the stacks
+    let (numbers, crate_rows) = stack_rows.split_last().unwrap();
+    let num_stacks = numbers.text.split_whitespace().count();
+
+    let mut stacks: Vec<Vec<char>> = vec![vec!(); num_stacks];
+    for row in crate_rows.iter().rev() {
+        for (idx, container) in row.chars().skip(1).step_by(4).enumerate() {
+            if container.text == " " {
+                continue;
+            }
+            if idx >= num_stacks {
+                return Err(container.error(format!("expected at most {num_stacks} stacks")));
+            }
+            if !container.text.bytes().all(|c| c.is_ascii_uppercase()) {
+                return Err(container.error("expected a crate from A-Z"));
+            }
+            stacks[idx].extend(container.text.chars());
+        }
+    }
+
+    let procedures = procedure_rows.iter()
+        .map(|row| {
+            let tokens = row.split(' ');
+            let stack = |idx: usize| {
+                let token = tokens.get(idx)?;
+                match token.parse::<usize>()? {
+                    num @ 1.. if num <= num_stacks => Ok(num - 1),
+                    _ => Err(token.error(format!("expected a stack from 1 to {num_stacks}"))),
+                }
+            };
+
+            tokens.get(0)?.expect("move")?;
+            tokens.get(2)?.expect("from")?;
+            tokens.get(4)?.expect("to")?;
+            Ok(Procedure {
+                num: tokens.parse(1)?,
+                from: stack(3)?,
+                to: stack(5)?,
+            })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((stacks, procedures))
+}
+
+fn doit<E>(input: &(Vec<Vec<char>>, Vec<Procedure>), extender: E) -> String
+    where E: Fn(&mut Vec<char>, &mut Vec<char>)
+{
+    let (mut stacks, procedures) = (input.0.clone(), &input.1);
+    for proc in procedures {
+        let mut to_move: Vec<char> = vec!();
+
+        let wanted_len = stacks[proc.from].len() - proc.num;
+        for element in stacks[proc.from].drain(wanted_len..) {
+            to_move.push(element);
+        }
+
+        extender(&mut stacks[proc.to], &mut to_move);
+    }
+
+    return stacks.iter()
+        .map(|stack| stack.last().unwrap())
+        .collect::<String>();
+}
+
+fn part1(input: &(Vec<Vec<char>>, Vec<Procedure>)) -> String {
+    return doit(input, |stack, values| { stack.extend(values.iter().rev()) });
+}
+
+fn part2(input: &(Vec<Vec<char>>, Vec<Procedure>)) -> String {
+    return doit(input, |stack, values| { stack.extend(values.iter()) });
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Vec<char>>, Vec<Procedure>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(rows: &Vec<String>) -> Result<(Vec<Vec<char>>, Vec<Procedure>), ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &(Vec<Vec<char>>, Vec<Procedure>)) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &(Vec<Vec<char>>, Vec<Procedure>)) -> String {
+        part2(input)
+    }
+}
+
+impl Generator for Day05 {
+    // counted in procedures
+    const SIZE: usize = 500;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        const NUM_STACKS: usize = 9;
+        let mut heights = (0..NUM_STACKS).map(|_| rng.range(2..=8) as usize).collect::<Vec<_>>();
+
+        let mut input = String::new();
+        for level in (0..*heights.iter().max().unwrap()).rev() {
+            let row = heights.iter()
+                .map(|&height| if level < height { format!("[{}]", (b'A' + rng.below(26) as u8) as char) } else { "   ".to_string() })
+                .collect::<Vec<_>>();
+            input += &format!("{}\n", row.join(" "));
+        }
+        let numbers = (1..=NUM_STACKS).map(|n| format!(" {n} ")).collect::<Vec<_>>();
+        input += &format!("{}\n\n", numbers.join(" "));
+
+        // no stack gets emptied, so that each has a crate on top in the end
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.below(NUM_STACKS);
+                if heights[from] > 1 {
+                    break from;
+                }
+            };
+            let to = (from + 1 + rng.below(NUM_STACKS - 1)) % NUM_STACKS;
+            let num = rng.range(1..=heights[from] as i64 - 1) as usize;
+            heights[from] -= num;
+            heights[to] += num;
+            input += &format!("move {num} from {} to {}\n", from + 1, to + 1);
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day05>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day05>(Day05::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::generate::streamed_input;
+use aoc_common::{end_of_input, lines, Generator, ParseError, Rng, Solution, Stream, StreamError, Streaming};
+
+fn parse(rows: &Vec<String>) -> Result<String, ParseError> {
+    let row = lines(rows).next().ok_or_else(|| end_of_input(rows).error("expected the datastream"))?;
+    match row.chars().find(|c| !c.text.bytes().all(|b| b.is_ascii_lowercase())) {
+        Some(c) => Err(c.error("expected a character from a-z")),
+        None => Ok(row.text.to_string()),
+    }
+}
+
+fn doit(row: &str, num_distinct: usize) -> usize {
+    assert!(row.len() >= num_distinct);
+
+    for idx in 0..row.len()-num_distinct-1 {
+        let chunk = &row[idx..idx+num_distinct];
+        if HashSet::<char>::from_iter(chunk.chars()).len() == num_distinct {
+            return idx + num_distinct;
+        }
+    }
+
+    panic!("No solution found");
+}
+
+/// Like [doit], keeping only the last `num_distinct` characters of the row.
+fn stream_marker(input: &mut Stream, num_distinct: usize) -> Result<usize, StreamError> {
+    let mut window = VecDeque::with_capacity(num_distinct);
+    let mut counts = [0usize; 26];
+    let mut distinct = 0;
+    let mut bytes = input.row_bytes();
+    let mut pos = 0;
+    while let Some(byte) = bytes.next() {
+        let byte = byte?;
+        if !byte.is_ascii_lowercase() {
+            return Err(bytes.error(byte, "expected a character from a-z"));
+        }
+        pos += 1;
+
+        if window.len() == num_distinct {
+            let old = window.pop_front().unwrap();
+            counts[(old - b'a') as usize] -= 1;
+            if counts[(old - b'a') as usize] == 0 {
+                distinct -= 1;
+            }
+        }
+        window.push_back(byte);
+        counts[(byte - b'a') as usize] += 1;
+        if counts[(byte - b'a') as usize] == 1 {
+            distinct += 1;
+        }
+
+        if distinct == num_distinct {
+            return Ok(pos);
+        }
+    }
+
+    panic!("No solution found");
+}
+
+fn part1(row: &str) -> usize {
+    doit(row, 4)
+}
+
+fn part2(row: &str) -> usize {
+    doit(row, 14)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<String, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &String) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &String) -> usize {
+        part2(input)
+    }
+}
+
+impl Streaming for Day06 {
+    fn stream_part1(input: &mut Stream) -> Result<usize, StreamError> {
+        stream_marker(input, 4)
+    }
+
+    fn stream_part2(input: &mut Stream) -> Result<usize, StreamError> {
+        stream_marker(input, 14)
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        // three letters never make a marker
+        input.extend((0..64).map(|_| *rng.choose(&['x', 'y', 'z'])));
+    }
+
+    fn generate_end(input: &mut String) {
+        input.push_str("abcdefghijklmnop\n");
+    }
+}
+
+impl Generator for Day06 {
+    // counted in chunks of 64 characters
+    const SIZE: usize = 64;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day06>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day06>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day06>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day06>(Day06::SIZE, &[1, 2]);
+    }
+}
@@ -1,59 +0,0 @@
-use std::collections::{HashSet};
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn readlines(filename: &str) -> Vec<String> {
-    let file = BufReader::new(File::open(&filename)
-        .expect(format!("File \"{filename}\" not found").as_ref()));
-    return file.lines().map(Result::unwrap).collect();
-}
-
-fn doit(row: &str, num_distinct: usize) -> usize {
-    assert!(row.len() >= num_distinct);
-
-    for idx in 0..row.len()-num_distinct-1 {
-        let chunk = &row[idx..idx+num_distinct];
-        if HashSet::<char>::from_iter(chunk.chars().into_iter()).len() == num_distinct {
-            return idx + num_distinct;
-        }
-    }
-
-    panic!("No solution found");
-}
-
-fn part1(row: &str) -> usize {
-    doit(row, 4)
-}
-
-fn part2(row: &str) -> usize {
-    doit(row, 14)
-}
-
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    let filename = if args.len() == 2 { &args[1] } else { "input" };
-    let lines = readlines(filename);
-    println!("Part 1: {}", part1(&lines[0]));
-    println!("Part 2: {}", part2(&lines[0]));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        assert_eq!(part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7);
-        assert_eq!(part1("bvwbjplbgvbhsrlpgdmjqwftvncz"), 5);
-        assert_eq!(part1("nppdvjthqldpwncqszvftbrmjlhg"), 6);
-        assert_eq!(part1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 10);
-        assert_eq!(part1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 11);
-
-        assert_eq!(part2("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 19);
-        assert_eq!(part2("bvwbjplbgvbhsrlpgdmjqwftvncz"), 23);
-        assert_eq!(part2("nppdvjthqldpwncqszvftbrmjlhg"), 23);
-        assert_eq!(part2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), 29);
-        assert_eq!(part2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), 26);
-    }
-}
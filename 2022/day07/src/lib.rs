@@ -0,0 +1,279 @@
+use std::collections::HashSet;
+
+use aoc_common::{lines, Generator, ParseError, Rng, Solution};
+
+use crate::sh::{Command, ListingEntry, parse_listing_line};
+
+mod fs {
+    pub struct File {
+        #[allow(dead_code)]
+        pub name: String,
+        pub size: u64,
+    }
+
+    pub struct Dir {
+        pub name: String,
+        pub dirs: Vec<Dir>,
+        pub files: Vec<File>,
+    }
+
+    impl Dir {
+        pub fn new(n: &str) -> Self {
+            Self {
+                name: n.to_string(),
+                dirs: vec!(),
+                files: vec!(),
+            }
+        }
+
+        pub fn add_dir(&mut self, name: &str) -> &mut Dir {
+            self.dirs.push(Dir::new(name));
+            return self.dirs.last_mut().unwrap();
+        }
+
+        pub fn add_file(&mut self, name: &str, size: u64) -> &mut File {
+            self.files.push(File { name: name.to_string(), size });
+            return self.files.last_mut().unwrap();
+        }
+
+        pub fn size(&self) -> u64 {
+            self.files.iter().map(|f| f.size).sum::<u64>()
+                + self.dirs.iter().map(|d| d.size()).sum::<u64>()
+        }
+    }
+}
+
+mod sh {
+    use aoc_common::{ParseError, Token};
+
+    pub enum Command {
+        CD { dest: String },
+        LS,
+    }
+
+    pub enum ListingEntry {
+        Command(Command),
+        Dir(String),
+        File(String, u64),
+    }
+
+    pub fn parse_listing_line(line: Token) -> Result<ListingEntry, ParseError> {
+        let token = line.split(' ');
+        if token.get(0)?.text == "$" {
+            let cmd = token.get(1)?;
+            return Ok(ListingEntry::Command(match cmd.text {
+                "cd" => Command::CD { dest: token.get(2)?.text.to_string() },
+                "ls" => Command::LS,
+                _ => return Err(cmd.error("unknown command"))
+            }));
+        }
+        if token.get(0)?.text == "dir" {
+            return Ok(ListingEntry::Dir(token.get(1)?.text.to_string()));
+        }
+        return Ok(ListingEntry::File(token.get(1)?.text.to_string(), token.parse(0)?));
+    }
+}
+
+fn process_terminal_output(rows: &Vec<String>) -> Result<fs::Dir, ParseError> {
+    let mut root = fs::Dir::new("/");
+    let mut current_dir = &mut root;
+    let mut last_cmd = "";
+    // TODO it would be easier to remember the parent of a directory and walk one node back;
+    // somehow I didn't get the lifetime of the back reference working
+    let mut visited_dirs: Vec<String> = vec!(current_dir.name.clone());
+
+    for line in lines(rows) {
+        match parse_listing_line(line)? {
+            ListingEntry::Command(cmd) => {
+                match cmd {
+                    Command::CD { dest } => {
+                        last_cmd = "cd";
+                        current_dir = match dest.as_str() {
+                            ".." => {
+                                visited_dirs.pop();
+                                let prev_dir = visited_dirs.iter().skip(1).fold(
+                                    &mut root,
+                                    |dir, to_visit| dir.dirs.iter_mut().find(|dir| dir.name == *to_visit).unwrap(),
+                                );
+                                // drop the prev dir as it will be added again later on as the current one
+                                visited_dirs.pop();
+                                prev_dir
+                            }
+                            "/" => {
+                                visited_dirs.clear();
+                                &mut root
+                            }
+                            dest => match current_dir.dirs.iter().position(|dir| dir.name == dest) {
+                                Some(pos) => &mut current_dir.dirs[pos],
+                                None => current_dir.add_dir(dest)
+                            }
+                        };
+                        visited_dirs.push(current_dir.name.clone());
+                    }
+                    Command::LS => last_cmd = "ls",
+                }
+            }
+            ListingEntry::Dir(_) | ListingEntry::File(_, _) if last_cmd != "ls" => {
+                return Err(line.error(format!("unexpected output in listing for mode {last_cmd}")));
+            }
+            ListingEntry::Dir(name) => {
+                current_dir.add_dir(&name);
+            }
+            ListingEntry::File(name, size) => {
+                current_dir.add_file(&name, size);
+            }
+        }
+    };
+
+    return Ok(root);
+}
+
+#[allow(dead_code)]
+fn print_dirs(dir: &fs::Dir, indent: &str) {
+    println!("{}{}: {}", indent, dir.name, dir.size());
+    for dir in dir.dirs.iter() {
+        print_dirs(dir, (" ".to_string() + indent).as_str())
+    }
+}
+
+fn find_dirs<'d, P>(dir: &'d fs::Dir, predicate: &'d P) -> Vec<&'d fs::Dir>
+    where P: Fn(&fs::Dir) -> bool
+{
+    let mut dirs = vec!();
+
+    if predicate(dir) {
+        dirs.push(dir);
+    }
+
+    for dir in dir.dirs.iter() {
+        dirs.extend(find_dirs(dir, predicate));
+    }
+
+    return dirs;
+}
+
+fn part1(root: &fs::Dir) -> u64 {
+    let dirs = find_dirs(root, &|dir| dir.size() < 100_000);
+    dirs.iter().map(|dir| dir.size()).sum()
+}
+
+fn part2(root: &fs::Dir) -> u64 {
+    const ALL_SIZE: u64 = 70000000;
+    const FREE_UPDATE_SIZE: u64 = 30000000;
+
+    let needed_size = FREE_UPDATE_SIZE - (ALL_SIZE - root.size());
+
+    let predicate = |dir: &fs::Dir| dir.size() >= needed_size;
+    let dirs = find_dirs(root, &predicate);
+
+    dirs.iter().map(|dir| dir.size()).min().unwrap()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = fs::Dir;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(rows: &Vec<String>) -> Result<fs::Dir, ParseError> {
+        process_terminal_output(rows)
+    }
+
+    fn part1(input: &fs::Dir) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &fs::Dir) -> u64 {
+        part2(input)
+    }
+}
+
+// a name of lowercase letters that isn't taken yet within the directory
+fn made_up_name(rng: &mut Rng, taken: &mut HashSet<String>, extension: bool) -> String {
+    loop {
+        let mut name = (0..rng.range(1..=8)).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+        if extension && rng.chance(0.7) {
+            name = format!("{name}.{}", rng.choose(&["txt", "dat", "log", "bin"]));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// lists the directory and walks into its subdirectories, like the session of the puzzle
+fn write_session(dir: usize, listings: &[Vec<String>], children: &[Vec<(usize, String)>], output: &mut String) {
+    *output += "$ ls\n";
+    for entry in &listings[dir] {
+        *output += &format!("{entry}\n");
+    }
+    for (child, name) in &children[dir] {
+        *output += &format!("$ cd {name}\n");
+        write_session(*child, listings, children, output);
+        *output += "$ cd ..\n";
+    }
+}
+
+impl Generator for Day07 {
+    // counted in directories
+    const SIZE: usize = 180;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let num_dirs = size.max(1);
+        let mut files = (0..num_dirs)
+            .map(|_| (0..rng.range(0..=4)).map(|_| rng.range(1..=300_000) as u64).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        // the update needs more space than is free, but the disk isn't overfull
+        let target = rng.range(40_000_001..=69_000_000) as u64;
+        let total = files.iter().flatten().sum::<u64>();
+        if total > target {
+            files.iter_mut().flatten().for_each(|size| *size = (*size * target / total).max(1));
+        }
+        let total = files.iter().flatten().sum::<u64>();
+        if total < target {
+            files[0].push(target - total);
+        }
+
+        // the root comes first, each directory is within one before it
+        let mut taken = vec![HashSet::new(); num_dirs];
+        let mut children = vec![vec!(); num_dirs];
+        let mut listings = vec![vec!(); num_dirs];
+        for dir in 1..num_dirs {
+            let parent = rng.below(dir);
+            let name = made_up_name(rng, &mut taken[parent], false);
+            listings[parent].push(format!("dir {name}"));
+            children[parent].push((dir, name));
+        }
+        for (dir, sizes) in files.iter().enumerate() {
+            for size in sizes {
+                let name = made_up_name(rng, &mut taken[dir], true);
+                listings[dir].push(format!("{size} {name}"));
+            }
+            rng.shuffle(&mut listings[dir]);
+        }
+
+        let mut output = "$ cd /\n".to_string();
+        write_session(0, &listings, &children, &mut output);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day07>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day07>(Day07::SIZE, &[1, 2]);
+    }
+}
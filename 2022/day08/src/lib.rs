@@ -0,0 +1,109 @@
+use std::cmp::max;
+
+use aoc_common::{lines, Direction, Generator, Grid, ParseError, Point2, Rng, Solution};
+
+struct Tree {
+    height: u8,
+    visible: bool,
+}
+
+fn parse(rows: &Vec<String>) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(lines(rows), |c| c.parse::<u8>())
+}
+
+fn find_first_equal_or_larger_in_sight(grid: &Grid<Tree>, from: Point2, direction: Direction) -> Option<Point2> {
+    let max_height = grid[from].height;
+    let mut pos = from + direction.vector();
+
+    while let Some(tree) = grid.get(pos) {
+        if tree.height >= max_height {
+            return Some(pos);
+        }
+        pos += direction.vector();
+    }
+    None
+}
+
+fn part1(heights: &Grid<u8>) -> usize {
+    let mut grid: Grid<Tree> = heights.map(|height| Tree { height: *height, visible: false });
+
+    for pos in heights.positions().map(Point2::from) {
+        grid[pos].visible = Direction::ALL.into_iter()
+            .any(|direction| find_first_equal_or_larger_in_sight(&grid, pos, direction).is_none());
+    }
+    grid.iter().filter(|(_, tree)| tree.visible).count()
+}
+
+fn part2(heights: &Grid<u8>) -> usize {
+    let grid: Grid<Tree> = heights.map(|height| Tree { height: *height, visible: false });
+    let (width, height) = (grid.width() as i64, grid.height() as i64);
+
+    let mut score = usize::MIN;
+
+    for pos in grid.positions().map(Point2::from) {
+        let mut crnt_score = 1usize;
+        for direction in Direction::ALL {
+            crnt_score *= match find_first_equal_or_larger_in_sight(&grid, pos, direction) {
+                Some(highest_pos) => pos.manhattan(highest_pos) as usize,
+                // the view reaches the edge
+                None => (match direction {
+                    Direction::Up => pos.y,
+                    Direction::Right => width - pos.x - 1,
+                    Direction::Down => height - pos.y - 1,
+                    Direction::Left => pos.x,
+                }) as usize,
+            }
+        }
+        score = max(score, crnt_score);
+    }
+    score
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Grid<u8>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Grid<u8>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Grid<u8>) -> usize {
+        part2(input)
+    }
+}
+
+impl Generator for Day08 {
+    // counted in trees along a side of the square forest
+    const SIZE: usize = 99;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| (0..size.max(1)).map(|_| (b'0' + rng.below(10) as u8) as char).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day08>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day08>(Day08::SIZE, &[1, 2]);
+    }
+}
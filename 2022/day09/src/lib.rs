@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use aoc_common::visualize::{self, Color, Frame, Pixel, Render};
+use aoc_common::{lines, Direction, Generator, Grid, ParseError, Point2, Rng, Solution};
+
+pub struct Movement {
+    direction: Direction,
+    steps: u32,
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Movement>, ParseError> {
+    lines(rows)
+        .map(|row| row.split(' '))
+        .map(|t| {
+            let (direction, steps) = (t.get(0)?, t.parse::<u32>(1)?);
+            let direction = match direction.text {
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                "R" => Direction::Right,
+                "U" => Direction::Up,
+                _ => return Err(direction.error("expected D, L, R or U"))
+            };
+            Ok(Movement { direction, steps })
+        }).collect()
+}
+
+struct Rope<'a> {
+    knots: &'a [Point2],
+    visited: &'a HashSet<Point2>,
+}
+
+impl Render for Rope<'_> {
+    // a window following the head, the knots are numbered from the head on
+    fn render(&self) -> Frame {
+        let (width, height) = (60, 30);
+        let corner = self.knots[0] - Point2::new(width / 2, height / 2);
+        let mut frame = Grid::new(width as usize, height as usize, Pixel::new('.', Color::GREY)).with_origin(corner.into());
+        for &pos in self.visited {
+            if let Some(pixel) = frame.get_mut(pos) {
+                *pixel = Pixel::new('#', Color::GREEN);
+            }
+        }
+        for (idx, &knot) in self.knots.iter().enumerate().rev() {
+            if let Some(pixel) = frame.get_mut(knot) {
+                *pixel = match idx {
+                    0 => Pixel::new('H', Color::RED),
+                    _ => Pixel::new(char::from_digit(idx as u32 % 10, 10).unwrap(), Color::YELLOW),
+                };
+            }
+        }
+        frame
+    }
+}
+
+fn find_num_visits_of_tail(movements: &Vec<Movement>, mut knots: Vec<Point2>) -> usize {
+    let mut visited: HashSet<Point2> = HashSet::new();
+    visited.insert(*knots.last().unwrap());
+
+    for movement in movements {
+        for _ in 0..movement.steps {
+            knots[0] += movement.direction.vector();
+
+            for idx in 1..knots.len() {
+                if knots[idx - 1].chebyshev(knots[idx]) > 1 {
+                    let step = (knots[idx - 1] - knots[idx]).signum();
+                    knots[idx] += step;
+                }
+            }
+
+            visited.insert(*knots.last().unwrap());
+        }
+        visualize::show(&Rope { knots: &knots, visited: &visited });
+    }
+
+    visited.len()
+}
+
+fn part1(movements: &Vec<Movement>) -> usize {
+    find_num_visits_of_tail(movements, vec![Point2::ORIGIN; 2])
+}
+
+fn part2(movements: &Vec<Movement>) -> usize {
+    find_num_visits_of_tail(movements, vec![Point2::ORIGIN; 10])
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Movement>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Movement>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Movement>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Movement>) -> usize {
+        part2(input)
+    }
+}
+
+impl Generator for Day09 {
+    // counted in motions
+    const SIZE: usize = 2000;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", rng.choose(&["D", "L", "R", "U"]), rng.range(1..=19)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day09>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day09>(Day09::SIZE, &[1, 2]);
+    }
+}
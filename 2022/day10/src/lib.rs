@@ -0,0 +1,134 @@
+use aoc_common::{lines, ocr, Answer, Generator, Grid, ParseError, Rng, Solution};
+
+pub enum Op {
+    NOOP,
+    ADDX(i32),
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Op>, ParseError> {
+    lines(rows)
+        .map(|row| row.split(' '))
+        .map(|tokens| {
+            let command = tokens.get(0)?;
+            Ok(match command.text {
+                "noop" => Op::NOOP,
+                "addx" => Op::ADDX(tokens.parse(1)?),
+                _ => return Err(command.error("unknown command"))
+            })
+        })
+        .collect()
+}
+
+fn process<C>(ops: &Vec<Op>, num_cycles_to_run: i64, mut cycle_callback: C)
+    where C: FnMut(i64, i64) {
+
+    let mut reg_x: i64 = 1;
+    let mut cycle: i64 = 0;
+
+    for op in ops {
+        let num_cycles = match op {
+            Op::NOOP => 1,
+            Op::ADDX(_) => 2
+        };
+        for _ in 0..num_cycles {
+            cycle += 1;
+            cycle_callback(cycle, reg_x);
+        }
+        match op {
+            Op::NOOP => (),
+            Op::ADDX(summand) => reg_x += *summand as i64
+        }
+        if cycle >= num_cycles_to_run {
+            break;
+        }
+    }
+}
+
+fn part1(ops: &Vec<Op>) -> i64 {
+    let mut sum_signal_strengths: i64 = 0;
+
+    process(ops, 220,|cycle, reg_x| {
+        if cycle == 20 || (cycle + 20) % 40 == 0 {
+            sum_signal_strengths += reg_x * cycle;
+        }
+    });
+
+    sum_signal_strengths
+}
+
+/// The letters on the screen, else the pixels if they don't show any known letters.
+fn part2(ops: &Vec<Op>) -> Answer {
+    let mut crt = Grid::new(40, 6, false);
+
+    process(ops, 240,|cycle, reg_x| {
+        let pixel = cycle as isize - 1;
+        // the last addx can finish after the screen is drawn
+        if let Some(lit) = crt.get_mut((pixel / 40, pixel % 40)) {
+            *lit = (reg_x - (cycle - 1) % 40).abs() <= 1;
+        }
+    });
+
+    match ocr::read(&crt) {
+        Some(letters) => Answer::Text(letters),
+        None => Answer::Glyphs(crt),
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Op>;
+    type Part1 = i64;
+    type Part2 = Answer;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Op>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Op>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Op>) -> Answer {
+        part2(input)
+    }
+}
+
+impl Generator for Day10 {
+    // counted in instructions
+    const SIZE: usize = 140;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        // the sprite stays on the screen, so that it draws something
+        let mut reg_x = 1;
+        let mut input = String::new();
+        for _ in 0..size {
+            let next = rng.range((reg_x - 15).max(0)..=(reg_x + 15).min(39));
+            if next == reg_x || rng.chance(0.3) {
+                input += "noop\n";
+            } else {
+                input += &format!("addx {}\n", next - reg_x);
+                reg_x = next;
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day10>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day10>(Day10::SIZE, &[1, 2]);
+    }
+}
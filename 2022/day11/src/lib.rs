@@ -0,0 +1,217 @@
+use aoc_common::{blocks, end_of_input, Generator, ParseError, Rng, Solution, Token};
+
+// TODO How to own a closure in a struct?
+//  This would help parsing the op only once per monkey.
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<usize>,
+    operation: Vec<String>,
+    test_mod: usize,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+fn last_number(line: Token, prefix: &str) -> Result<usize, ParseError> {
+    line.trim().strip_prefix(prefix)?.parse()
+}
+
+fn parse_operation(line: Token) -> Result<Vec<String>, ParseError> {
+    let tokens = line.trim().strip_prefix("Operation: new = ")?.split(' ');
+    if tokens.len() != 3 {
+        return Err(line.error("expected an operation like \"old * 19\""));
+    }
+    for operand in [tokens.get(0)?, tokens.get(2)?] {
+        if operand.text != "old" {
+            operand.parse::<usize>()?;
+        }
+    }
+    let op = tokens.get(1)?;
+    if !["+", "-", "*", "/"].contains(&op.text) {
+        return Err(op.error("expected +, -, * or /"));
+    }
+    Ok(tokens.iter().map(|t| t.text.to_string()).collect())
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys: Vec<Monkey> = vec!();
+
+    for monkey_desc in blocks(rows) {
+        let [header, items, operation, test, throw_true, throw_false] = monkey_desc.as_slice() else {
+            return Err(monkey_desc[0].error("expected a monkey description of 6 lines"));
+        };
+        let which = header.strip_prefix("Monkey ")?.strip_suffix(":")?;
+        if which.parse::<usize>()? != monkeys.len() {
+            return Err(which.error(format!("expected monkey {}", monkeys.len())));
+        }
+
+        monkeys.push(Monkey {
+            items: items.trim().strip_prefix("Starting items: ")?.split(',')
+                .iter().map(|x| x.trim().parse()).collect::<Result<_, _>>()?,
+            operation: parse_operation(*operation)?,
+            test_mod: last_number(*test, "Test: divisible by ")?,
+            throw_true: last_number(*throw_true, "If true: throw to monkey ")?,
+            throw_false: last_number(*throw_false, "If false: throw to monkey ")?,
+        });
+    }
+
+    for monkey in &monkeys {
+        if monkey.throw_true.max(monkey.throw_false) >= monkeys.len() {
+            return Err(end_of_input(rows).error(format!("monkeys only throw to one of the {} monkeys", monkeys.len())));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn compute_new_level(old: usize, op_tokens: &Vec<String>) -> usize {
+    let left = if op_tokens[0] == "old" { old } else { op_tokens[0].parse().unwrap() };
+    let right = if op_tokens[2] == "old" { old } else { op_tokens[2].parse().unwrap() };
+    match op_tokens[1].as_str() {
+        "+" => left + right,
+        "-" => left - right,
+        "*" => left * right,
+        "/" => left / right,
+        op => panic!("Unknown op {op}")
+    }
+}
+
+fn doit<F>(monkeys: &mut Vec<Monkey>, num_rounds: usize, update_lvl: F) -> usize
+    where F: Fn(usize) -> usize
+{
+    let mut num_inspections = vec![0; monkeys.len()];
+
+    for _ in 0..num_rounds {
+        for monkey_idx in 0..monkeys.len() {
+            for item in monkeys[monkey_idx].items.clone() {
+                num_inspections[monkey_idx] += 1;
+                let monkey = &monkeys[monkey_idx];
+                let mut lvl: usize = item;
+                lvl = compute_new_level(lvl, &monkey.operation);
+                lvl = update_lvl(lvl);
+                let dest = if lvl.is_multiple_of(monkey.test_mod) {
+                    monkey.throw_true
+                } else {
+                    monkey.throw_false
+                };
+                monkeys[dest].items.push(lvl);
+            }
+            monkeys[monkey_idx].items.clear();
+        }
+    }
+
+    num_inspections.sort();
+    num_inspections.iter().rev().take(2).fold(1, |a, b| a * *b)
+}
+
+fn part1(monkeys: &Vec<Monkey>) -> usize {
+    let mut monkeys = monkeys.clone();
+    doit(&mut monkeys, 20, |lvl| lvl / 3)
+}
+
+fn part2(monkeys: &Vec<Monkey>) -> usize {
+    let mut monkeys = monkeys.clone();
+    // jep, that may not really be the lcm, but it's good enough
+    let lcm = monkeys.iter().map(|m| m.test_mod).product::<usize>();
+    doit(&mut monkeys, 10000, |lvl| lvl % lcm)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Monkey>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Monkey>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Monkey>) -> usize {
+        part2(input)
+    }
+}
+
+// whether a worry level of part 1 outgrows a usize, the real inputs avoid that
+fn worries_overflow(monkeys: &Vec<Monkey>) -> bool {
+    let mut monkeys = monkeys.clone();
+    for _ in 0..20 {
+        for monkey_idx in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[monkey_idx].items) {
+                let monkey = &monkeys[monkey_idx];
+                let operand = |token: &str| if token == "old" { item } else { token.parse().unwrap() };
+                let (left, right) = (operand(&monkey.operation[0]), operand(&monkey.operation[2]));
+                let Some(lvl) = (if monkey.operation[1] == "*" { left.checked_mul(right) } else { left.checked_add(right) }) else {
+                    return true;
+                };
+                let lvl = lvl / 3;
+                let dest = if lvl.is_multiple_of(monkey.test_mod) { monkey.throw_true } else { monkey.throw_false };
+                monkeys[dest].items.push(lvl);
+            }
+        }
+    }
+    false
+}
+
+impl Generator for Day11 {
+    // counted in monkeys, at most 9 so that part 2 doesn't overflow
+    const SIZE: usize = 8;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let num_monkeys = size.clamp(2, 9);
+        loop {
+            let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+            rng.shuffle(&mut primes);
+            // like the real inputs, one monkey squares the worry level and two multiply it
+            let mut operations = (0..num_monkeys)
+                .map(|idx| match idx {
+                    0 => "old * old".to_string(),
+                    1 | 2 => format!("old * {}", rng.range(2..=19)),
+                    _ => format!("old + {}", rng.range(1..=8)),
+                })
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut operations);
+
+            let mut input = vec!();
+            for (idx, operation) in operations.iter().enumerate() {
+                let items = (0..rng.range(1..=8)).map(|_| rng.range(50..=99).to_string()).collect::<Vec<_>>();
+                let throw_true = (idx + 1 + rng.below(num_monkeys - 1)) % num_monkeys;
+                let throw_false = loop {
+                    let other = rng.below(num_monkeys);
+                    if other != idx && (other != throw_true || num_monkeys == 2) {
+                        break other;
+                    }
+                };
+                input.push(format!("Monkey {idx}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {}\n    If true: throw to monkey {throw_true}\n    If false: throw to monkey {throw_false}\n",
+                                   items.join(", "), primes[idx]));
+            }
+            let input = input.join("\n");
+
+            let rows = input.lines().map(String::from).collect();
+            if !worries_overflow(&parse(&rows).expect("A valid input")) {
+                return input;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day11>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day11>(Day11::SIZE, &[1, 2]);
+    }
+}
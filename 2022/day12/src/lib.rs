@@ -0,0 +1,137 @@
+use aoc_common::search::bfs;
+use aoc_common::{end_of_input, lines, Generator, Grid, ParseError, Rng, Solution};
+
+pub struct Heightmap {
+    heights: Grid<u8>,
+    start: (isize, isize),
+    end: (isize, isize),
+}
+
+fn parse(rows: &Vec<String>) -> Result<Heightmap, ParseError> {
+    let (mut start, mut end) = (None, None);
+    let heights = Grid::parse(lines(rows), |height| match height.text.as_bytes() {
+        b"S" => {
+            start = Some((height.line as isize - 1, height.column as isize - 1));
+            Ok(0)
+        }
+        b"E" => {
+            end = Some((height.line as isize - 1, height.column as isize - 1));
+            Ok(b'z' - b'a')
+        }
+        [c @ b'a'..=b'z'] => Ok(c - b'a'),
+        _ => Err(height.error("expected a height from a to z, S or E")),
+    })?;
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok(Heightmap { heights, start, end }),
+        _ => Err(end_of_input(rows).error("expected a start S and an end E")),
+    }
+}
+
+// every step can climb at most one level up
+fn climbable<'a>(map: &'a Heightmap, pos: &(isize, isize)) -> impl Iterator<Item=(isize, isize)> + 'a {
+    let pos = *pos;
+    map.heights.neighbours4(pos)
+        .filter(move |&next| map.heights[next] as i8 - map.heights[pos] as i8 <= 1)
+}
+
+fn find_shortest_path(map: &Heightmap, starts: impl IntoIterator<Item=(isize, isize)>) -> Option<Vec<(isize, isize)>> {
+    let paths = bfs(starts, |pos| climbable(map, pos), |&pos| pos == map.end);
+    paths.path(paths.goal()?)
+}
+
+fn part1(map: &Heightmap) -> usize {
+    find_shortest_path(map, [map.start]).unwrap().len() - 1
+}
+
+fn part2(map: &Heightmap) -> usize {
+    // searching from all of them at once finds the closest one
+    let starting_points = map.heights.iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(pos, _)| pos);
+    find_shortest_path(map, starting_points).unwrap().len() - 1
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Heightmap, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Heightmap) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Heightmap) -> usize {
+        part2(input)
+    }
+}
+
+impl Generator for Day12 {
+    // counted in columns, at least 26 to climb from a to z
+    const SIZE: usize = 160;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let (width, height) = (size.max(26), (size / 4).max(5));
+
+        // a path from the left edge to the end which goes right or straight up or down, so that it
+        // never crosses itself
+        let end = (rng.below(height), 25 + rng.below(width - 25));
+        let mut path = vec!((rng.below(height), 0));
+        for col in 0..=end.1 {
+            let mut row = path.last().unwrap().0;
+            let target = if col == end.1 { end.0 } else { rng.below(height) };
+            while row != target {
+                row = if row < target { row + 1 } else { row - 1 };
+                path.push((row, col));
+            }
+            if col < end.1 {
+                path.push((row, col + 1));
+            }
+        }
+
+        // climbing at most one step at a time along the path, elsewhere the hills rise towards the right
+        let mut heights = Grid::new(width, height, None);
+        for (step, &(row, col)) in path.iter().enumerate() {
+            heights[(row as isize, col as isize)] = Some((step * 25 / (path.len() - 1)) as u8);
+        }
+        let mut input = String::new();
+        for row in 0..height {
+            for col in 0..width {
+                let pos = (row as isize, col as isize);
+                let c = match heights[pos] {
+                    _ if (row, col) == path[0] => 'S',
+                    _ if (row, col) == end => 'E',
+                    Some(height) => (b'a' + height) as char,
+                    None => (b'a' + rng.range(0..=(col * 25 / width + 2).min(25) as i64) as u8) as char,
+                };
+                input.push(c);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day12>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day12>(Day12::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+
+use aoc_common::{blocks, Generator, ParseError, Rng, Solution, Token};
+
+type Pair = (String, String);
+
+fn parse_packet(packet: Token) -> Result<String, ParseError> {
+    let mut depth: usize = 0;
+    for c in packet.chars() {
+        match c.text {
+            "[" => depth += 1,
+            "]" if depth > 0 => depth -= 1,
+            "," | "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" if depth > 0 => (),
+            _ => return Err(c.error("expected a list of integers and lists")),
+        }
+        if depth == 0 && c.column != packet.column + packet.text.len() - 1 {
+            return Err(c.error("expected the end of the packet"));
+        }
+    }
+    if depth != 0 {
+        return Err(packet.end().error("expected \"]\""));
+    }
+    Ok(packet.text.to_string())
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Pair>, ParseError> {
+    blocks(rows).iter()
+        .map(|pair| match pair.as_slice() {
+            [left, right] => Ok((parse_packet(*left)?, parse_packet(*right)?)),
+            _ => Err(pair[0].error("expected a pair of packets")),
+        })
+        .collect()
+}
+
+fn find_token_end(data: &[u8], start: usize) -> usize {
+    let mut pos = start;
+
+    if data[pos] == '[' as u8 {
+        pos += 1;
+        while data[pos] != ']' as u8 {
+            if data[pos] == '[' as u8 {
+                pos = find_token_end(data, pos) + 1;
+            } else {
+                pos += 1;
+            }
+        }
+    } else {
+        while pos < data.len() && data[pos] != ',' as u8 {
+            pos += 1;
+        }
+    }
+
+    pos
+}
+
+fn is_in_order(left: &[u8], right: &[u8]) -> Option<bool> {
+    let mut l: usize = 0;
+    let mut r: usize = 0;
+
+    while l < left.len() && r < right.len() {
+        if left[l] == ',' as u8 {
+            l += 1;
+        }
+        if right[r] == ',' as u8 {
+            r += 1;
+        }
+
+        let lstart = if left[l] == '[' as u8 { l + 1 } else { l };
+        let rstart = if right[r] == '[' as u8 { r + 1 } else { r };
+        let lend = find_token_end(left, l);
+        let rend = find_token_end(right, r);
+
+        if left[l] == '[' as u8 || right[r] == '[' as u8 {
+            if let Some(result) = is_in_order(&left[lstart..lend], &right[rstart..rend]) {
+                return Some(result);
+            }
+        } else {
+            let diff = String::from_utf8_lossy(&right[rstart..rend]).parse::<i32>().unwrap()
+                - String::from_utf8_lossy(&left[lstart..lend]).parse::<i32>().unwrap();
+
+            if diff != 0 {
+                return Some(diff > 0);
+            }
+        }
+
+        l = lend + 1;
+        r = rend + 1;
+    }
+
+    if l >= left.len() && r >= right.len() { None } else { Some(l >= left.len()) }
+}
+
+fn part1(pairs: &Vec<Pair>) -> usize {
+    let mut sum: usize = 0;
+
+    for (idx, pair) in pairs.iter().enumerate() {
+        if let Some(true) = is_in_order(pair.0.as_bytes(), pair.1.as_bytes()) {
+            sum += idx + 1
+        }
+    }
+
+    sum
+}
+
+fn part2(pairs: &Vec<Pair>) -> usize {
+    let mut relevant = vec!["[[2]]".as_bytes(), "[[6]]".as_bytes()];
+    relevant.extend(pairs.iter()
+        .flat_map(|pair| [pair.0.as_bytes(), pair.1.as_bytes()]));
+
+    relevant.sort_by(|&a, &b| match is_in_order(a, b) {
+        Some(result) => if result { Ordering::Less } else { Ordering::Greater },
+        None => Ordering::Equal
+    });
+
+    relevant.iter().enumerate()
+        .filter(|(_, &value)| value == "[[2]]".as_bytes() || value == "[[6]]".as_bytes())
+        .map(|(idx, _)| idx + 1)
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Pair>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Pair>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Pair>) -> usize {
+        part2(input)
+    }
+}
+
+fn made_up_packet(rng: &mut Rng, depth: usize) -> String {
+    let items = (0..rng.range(0..=5))
+        .map(|_| if depth < 4 && rng.chance(0.3) { made_up_packet(rng, depth + 1) } else { rng.range(0..=10).to_string() })
+        .collect::<Vec<_>>();
+    format!("[{}]", items.join(","))
+}
+
+impl Generator for Day13 {
+    // counted in pairs of packets
+    const SIZE: usize = 150;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}\n{}\n", made_up_packet(rng, 0), made_up_packet(rng, 0)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day13>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day13>(Day13::SIZE, &[1, 2]);
+    }
+}
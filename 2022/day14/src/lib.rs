@@ -0,0 +1,177 @@
+use aoc_common::visualize::{self, Color, Frame, Pixel, Render};
+use aoc_common::{Generator, Grid, ParseError, Rng, Solution, Token};
+
+fn parse_path(row: Token) -> Result<Vec<(usize, usize)>, ParseError> {
+    let tokens = row.split(' ');
+    let mut points: Vec<(usize, usize)> = vec!();
+    for (idx, token) in tokens.iter().enumerate() {
+        if idx % 2 == 1 {
+            token.expect("->")?;
+            continue;
+        }
+        let (x, y) = token.split_once(",")?;
+        let point = (x.parse()?, y.parse()?);
+        if let Some(&prev) = points.last() {
+            if prev.0 != point.0 && prev.1 != point.1 {
+                return Err(token.error("expected a horizontal or vertical line"));
+            }
+        }
+        points.push(point);
+    }
+    if tokens.len().is_multiple_of(2) {
+        return Err(row.end().error("expected a point"));
+    }
+    Ok(points)
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<((usize, usize), (usize, usize))>, ParseError> {
+    let mut lines: Vec<((usize, usize), (usize, usize))> = vec!();
+    for row in aoc_common::lines(rows) {
+        let points = parse_path(row)?;
+        lines.extend(points.iter().zip(points.iter().skip(1)).map(|(&a, &b)| (a, b)));
+    }
+    Ok(lines)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+struct Cave<'a>(&'a Grid<Tile>);
+
+impl Render for Cave<'_> {
+    fn render(&self) -> Frame {
+        self.0.map(|tile| match tile {
+            Tile::Air => Pixel::EMPTY,
+            Tile::Rock => Pixel::new('#', Color::GREY),
+            Tile::Sand => Pixel::new('o', Color::YELLOW),
+        })
+    }
+}
+
+// the map spans from the source down to the lowest rock, sand leaving it falls into the abyss
+fn render_rocks(lines: &Vec<((usize, usize), (usize, usize))>) -> Grid<Tile> {
+    let min_x = lines.iter().flat_map(|n| [n.0.0, n.1.0]).min().unwrap().min(500);
+    let max_x = lines.iter().flat_map(|n| [n.0.0, n.1.0]).max().unwrap().max(500);
+    let max_y = lines.iter().flat_map(|n| [n.0.1, n.1.1]).max().unwrap();
+    let mut grid = Grid::new(max_x - min_x + 1, max_y + 1, Tile::Air).with_origin((0, min_x as isize));
+
+    for (start, end) in lines {
+        let rows = if start.1 <= end.1 { start.1..=end.1 } else { end.1..=start.1 };
+        let cols = if start.0 <= end.0 { start.0..=end.0 } else { end.0..=start.0 };
+        for row in rows {
+            for col in cols.clone() {
+                grid[(row as isize, col as isize)] = Tile::Rock;
+            }
+        }
+    }
+
+    grid
+}
+
+fn doit(lines: &Vec<((usize, usize), (usize, usize))>) -> usize {
+    let mut grid = render_rocks(lines);
+    let source = (0, 500);
+
+    let mut num_resting = 0;
+    while grid[source] == Tile::Air {
+        let mut pos = source;
+        loop {
+            let next = [(1, 0), (1, -1), (1, 1)].iter()
+                .map(|dir| (pos.0 + dir.0, pos.1 + dir.1))
+                .find(|&new_pos| grid.get(new_pos).is_none_or(|&tile| tile == Tile::Air));
+
+            match next {
+                Some(new_pos) if grid.contains(new_pos) => pos = new_pos,
+                Some(_) => return num_resting,
+                None => {
+                    grid[pos] = Tile::Sand;
+                    num_resting += 1;
+                    visualize::show(&Cave(&grid));
+                    break;
+                }
+            }
+        }
+    }
+
+    num_resting
+}
+
+fn part1(lines: &Vec<((usize, usize), (usize, usize))>) -> usize {
+    doit(lines)
+}
+
+fn part2(lines: &Vec<((usize, usize), (usize, usize))>) -> usize {
+    let mut lines = lines.clone();
+    let max_y = lines.iter().flat_map(|n| [n.0.1, n.1.1]).max().unwrap() + 2;
+    // sand can't spread further to the sides than it falls
+    lines.push(((500usize.saturating_sub(max_y), max_y), (500 + max_y, max_y)));
+    doit(&lines)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<((usize, usize), (usize, usize))>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<((usize, usize), (usize, usize))>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<((usize, usize), (usize, usize))>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<((usize, usize), (usize, usize))>) -> usize {
+        part2(input)
+    }
+}
+
+impl Generator for Day14 {
+    // counted in paths of rock
+    const SIZE: usize = 150;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            // below the source of the sand, turning at each point
+            let mut point = (rng.range(450..=550), rng.range(10..=170));
+            let mut points = vec!(format!("{},{}", point.0, point.1));
+            let horizontal = rng.chance(0.5);
+            for idx in 0..rng.range(1..=6) {
+                let length = rng.range(1..=10) * if rng.chance(0.5) { 1 } else { -1 };
+                if (idx % 2 == 0) == horizontal {
+                    point.0 += length;
+                } else {
+                    point.1 = (point.1 + length).max(1);
+                }
+                points.push(format!("{},{}", point.0, point.1));
+            }
+            input += &format!("{}\n", points.join(" -> "));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day14>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day14>(Day14::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,214 @@
+use aoc_common::parallel;
+use aoc_common::{lines, Answer, Generator, IntervalSet, ParseError, Point2, Reference, Rng, Solution, Tokens};
+
+fn coordinate(tokens: &Tokens, idx: usize, prefix: &str, suffix: &str) -> Result<i64, ParseError> {
+    tokens.get(idx)?.strip_prefix(prefix)?.strip_suffix(suffix)?.parse()
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<(Point2, Point2)>, ParseError> {
+    lines(rows)
+        .map(|row| row.split(' '))
+        .map(|tokens| Ok((
+            Point2::new(coordinate(&tokens, 2, "x=", ",")?, coordinate(&tokens, 3, "y=", ":")?),
+            Point2::new(coordinate(&tokens, 8, "x=", ",")?, coordinate(&tokens, 9, "y=", "")?)
+        ))).collect()
+}
+
+fn find_excluded_ranges(sensors_with_beacons: &Vec<(Point2, Point2)>, row_of_interest: i64) -> IntervalSet {
+    let mut excluded_ranges = IntervalSet::new();
+
+    for sensor_with_beacon in sensors_with_beacons {
+        let (sensor, beacon) = *sensor_with_beacon;
+        let dist_to_beacon = sensor.manhattan(beacon);
+        let dist_to_row_of_interest = sensor.manhattan(Point2::new(sensor.x, row_of_interest));
+
+        if dist_to_row_of_interest <= dist_to_beacon {
+            let x_range = dist_to_beacon - dist_to_row_of_interest;
+            excluded_ranges.insert(sensor.x - x_range..=sensor.x + x_range);
+        }
+    }
+
+    excluded_ranges
+}
+
+fn part1(sensors_with_beacons: &Vec<(Point2, Point2)>, row_of_interest: i64) -> i64 {
+    let mut ranges = find_excluded_ranges(sensors_with_beacons, row_of_interest);
+    for (_, beacon) in sensors_with_beacons.iter().filter(|(_, beacon)| beacon.y == row_of_interest) {
+        ranges.remove(beacon.x..=beacon.x);
+    }
+
+    ranges.len() as i64
+}
+
+fn part2(sensors_with_beacons: &Vec<(Point2, Point2)>, searchspace: ((i64, i64), (i64, i64))) -> i64 {
+    part2_on(parallel::threads(), sensors_with_beacons, searchspace)
+}
+
+fn part2_on(threads: usize, sensors_with_beacons: &Vec<(Point2, Point2)>, searchspace: ((i64, i64), (i64, i64))) -> i64 {
+    parallel::find_first_on(threads, searchspace.1.0..=searchspace.1.1, |y| {
+        let ranges = find_excluded_ranges(sensors_with_beacons, y);
+        ranges.gaps(searchspace.0.0..=searchspace.0.1).ranges().next()
+            .map(|gap| gap.start() * 4000000 + y)
+    }).expect("Didn't find a solution")
+}
+
+pub struct Day15;
+
+// the search space of the example, which the naive parts go over
+const EXAMPLE_LIMIT: i64 = 20;
+
+impl Solution for Day15 {
+    type Input = Vec<(Point2, Point2)>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<(Point2, Point2)>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<(Point2, Point2)>) -> i64 {
+        part1(input, 2000000)
+    }
+
+    fn part2(input: &Vec<(Point2, Point2)>) -> i64 {
+        part2(input, ((0, 4000000), (0, 4000000)))
+    }
+
+    fn example_part1(input: &Vec<(Point2, Point2)>) -> i64 {
+        part1(input, EXAMPLE_LIMIT / 2)
+    }
+
+    fn example_part2(input: &Vec<(Point2, Point2)>) -> i64 {
+        part2(input, ((0, EXAMPLE_LIMIT), (0, EXAMPLE_LIMIT)))
+    }
+}
+
+// every position of the row checked against every sensor
+fn naive_part1(sensors_with_beacons: &Vec<(Point2, Point2)>, row_of_interest: i64) -> i64 {
+    let reach = |(sensor, beacon): &(Point2, Point2)| sensor.manhattan(*beacon);
+    let min_x = sensors_with_beacons.iter().map(|pair| pair.0.x - reach(pair)).min().expect("There are sensors");
+    let max_x = sensors_with_beacons.iter().map(|pair| pair.0.x + reach(pair)).max().expect("There are sensors");
+
+    (min_x..=max_x).map(|x| Point2::new(x, row_of_interest))
+        .filter(|&pos| sensors_with_beacons.iter().any(|pair| pair.0.manhattan(pos) <= reach(pair)))
+        .filter(|&pos| sensors_with_beacons.iter().all(|&(_, beacon)| beacon != pos))
+        .count() as i64
+}
+
+// every position of the search space checked against every sensor, row by row
+fn naive_part2(sensors_with_beacons: &Vec<(Point2, Point2)>, limit: i64) -> i64 {
+    (0..=limit).flat_map(|y| (0..=limit).map(move |x| Point2::new(x, y)))
+        .find(|&pos| sensors_with_beacons.iter().all(|&(sensor, beacon)| sensor.manhattan(pos) > sensor.manhattan(beacon)))
+        .map(|pos| pos.x * 4000000 + pos.y)
+        .expect("Didn't find a solution")
+}
+
+/// The sensors with their closest beacons, which leave a single position within the search space
+/// of part 2 (from 0 to the limit both ways) uncovered, and that position.
+fn made_up_sensors(rng: &mut Rng, num_sensors: usize, limit: i64) -> (Vec<(Point2, Point2)>, Point2) {
+    let hidden = Point2::new(rng.range(0..=limit), rng.range(0..=limit));
+
+    // a sensor diagonally off the hidden beacon in each direction covers the quarter of the search
+    // space on its side, the borders to the other quarters included, but not the hidden beacon
+    let mut sensors = vec!();
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = hidden + Point2::new(dx * limit, dy * limit);
+        sensors.push((sensor, sensor - Point2::new(dx * (2 * limit - 1), 0)));
+    }
+
+    // the others see a beacon closer than the hidden one
+    while sensors.len() < num_sensors {
+        let sensor = Point2::new(rng.range(-limit / 10..=limit * 11 / 10), rng.range(-limit / 10..=limit * 11 / 10));
+        let range = sensor.manhattan(hidden) - 1;
+        if range < 1 {
+            continue;
+        }
+        let distance = rng.range(1..=range.min(limit / 4));
+        let dx = rng.range(-distance..=distance);
+        let dy = (distance - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensors.push((sensor, sensor + Point2::new(dx, dy)));
+    }
+    rng.shuffle(&mut sensors);
+
+    (sensors, hidden)
+}
+
+fn format_sensors(sensors_with_beacons: &[(Point2, Point2)]) -> String {
+    sensors_with_beacons.iter()
+        .map(|(sensor, beacon)| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                                        sensor.x, sensor.y, beacon.x, beacon.y))
+        .collect()
+}
+
+impl Generator for Day15 {
+    // counted in sensors, at least the four around the distress beacon
+    const SIZE: usize = 30;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        format_sensors(&made_up_sensors(rng, size, 4000000).0)
+    }
+}
+
+impl Reference for Day15 {
+    const PARTS: &'static [u8] = &[1, 2];
+    const REFERENCE_SIZE: usize = 10;
+
+    fn reference_input(rng: &mut Rng, size: usize) -> String {
+        format_sensors(&made_up_sensors(rng, size, EXAMPLE_LIMIT).0)
+    }
+
+    fn clever(sensors_with_beacons: &Vec<(Point2, Point2)>, part: u8) -> Answer {
+        match part {
+            1 => part1(sensors_with_beacons, EXAMPLE_LIMIT / 2).into(),
+            _ => part2(sensors_with_beacons, ((0, EXAMPLE_LIMIT), (0, EXAMPLE_LIMIT))).into(),
+        }
+    }
+
+    fn naive(sensors_with_beacons: &Vec<(Point2, Point2)>, part: u8) -> Answer {
+        match part {
+            1 => naive_part1(sensors_with_beacons, EXAMPLE_LIMIT / 2).into(),
+            _ => naive_part2(sensors_with_beacons, EXAMPLE_LIMIT).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+    use aoc_common::reference::check_reference;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day15>("examples");
+    }
+
+    #[test]
+    fn made_up_sensors_hide_a_single_beacon() {
+        let mut rng = Rng::new(7);
+        for _ in 0..3 {
+            let (sensors, hidden) = made_up_sensors(&mut rng, Day15::SIZE, 4000000);
+            assert_eq!(part2(&sensors, ((0, 4000000), (hidden.y, hidden.y))), hidden.x * 4000000 + hidden.y);
+            for y in [0, hidden.y - 1, hidden.y + 1, 4000000] {
+                assert!(find_excluded_ranges(&sensors, y).gaps(0..=4000000).ranges().next().is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day15>(Day15::SIZE, &[1, 2]);
+        // part 2 stops the threads searching other rows once one finds the beacon
+        for seed in 1..=3 {
+            let (sensors, hidden) = made_up_sensors(&mut Rng::new(seed), Day15::SIZE, 4000000);
+            assert_eq!(part2_on(4, &sensors, ((0, 4000000), (0, 4000000))), hidden.x * 4000000 + hidden.y);
+        }
+    }
+
+    #[test]
+    fn naive_parts() {
+        check_reference::<Day15>(Day15::REFERENCE_SIZE, 1..=50);
+    }
+}
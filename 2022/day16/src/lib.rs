@@ -0,0 +1,228 @@
+use std::cmp::max;
+use std::collections::HashMap;
+
+use aoc_common::parallel;
+use aoc_common::search::all_pairs;
+use aoc_common::{end_of_input, lines, Generator, Memo, ParseError, Rng, Solution, Token};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Valve {
+    name: String,
+    flow_rate: u64,
+    tunnels: Vec<String>,
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Valve>, ParseError> {
+    let mut tunnel_tokens: Vec<Token> = vec!();
+    let valves: Vec<Valve> = lines(rows)
+        .map(|row| {
+            let tokens = row.split(' ');
+            tokens.get(0)?.expect("Valve")?;
+            tunnel_tokens.extend(tokens.iter().skip(9).map(|t| t.trim_matches(|c| c == ',')));
+            Ok(Valve {
+                name: tokens.get(1)?.text.to_string(),
+                flow_rate: tokens.get(4)?.strip_prefix("rate=")?.strip_suffix(";")?.parse()?,
+                tunnels: tokens.iter().skip(9).map(|t| t.trim_matches(|c| c == ',').text.to_string()).collect(),
+            })
+        }).collect::<Result<_, _>>()?;
+
+    if let Some(unknown) = tunnel_tokens.iter().find(|t| valves.iter().all(|v| v.name != t.text)) {
+        return Err(unknown.error("tunnel leads to an unknown valve"));
+    }
+    if valves.iter().all(|v| v.name != "AA") {
+        return Err(end_of_input(rows).error("expected the valve AA to start at"));
+    }
+
+    Ok(valves)
+}
+
+fn find_distances(valves: &Vec<Valve>) -> Vec<Vec<Option<u64>>> {
+    let index_by_name: HashMap<&str, usize> = valves.iter().enumerate()
+        .map(|(idx, v)| (v.name.as_str(), idx))
+        .collect();
+    all_pairs(valves.len(), |idx| valves[idx].tunnels.iter().map(|t| (index_by_name[t.as_str()], 1)))
+}
+
+fn find_shortest_cached_path(valves: &Vec<Valve>,
+                             distances: &Vec<Vec<Option<u64>>>,
+                             memo: &mut Memo<(u64, usize, usize), u64>,
+                             initial_remaining_minutes: u64,
+                             start_idx: usize,
+                             to_visit: &[usize]) -> u64 {
+    if initial_remaining_minutes == 0 {
+        return 0;
+    }
+
+    let reachable = to_visit.iter()
+        // + 1 for opening the valve and + 1 for at least one minute left after opening the valve
+        .filter(|&&i| distances[start_idx][i].is_some_and(|dist| dist + 2 <= initial_remaining_minutes))
+        .cloned()
+        .collect_vec();
+
+    let key = (initial_remaining_minutes, start_idx, reachable.iter()
+        .fold(0, |bits, idx| bits | (1 << idx)));
+
+    memo.get_or_compute(key, |memo| {
+        let mut best = valves[start_idx].flow_rate * (initial_remaining_minutes - 1);
+
+        for &dest_idx in &reachable {
+            let remaining_to_visit = reachable.clone().into_iter()
+                .filter(|&i| i != dest_idx)
+                .collect_vec();
+            let mut remaining_minutes = initial_remaining_minutes;
+            let mut score = 0;
+
+            if valves[start_idx].flow_rate > 0 {
+                remaining_minutes -= 1;
+                score += valves[start_idx].flow_rate * remaining_minutes;
+            };
+
+            let dist = distances[start_idx][dest_idx].unwrap();
+            if dist + 2 < remaining_minutes {
+                remaining_minutes -= dist;
+
+                score += find_shortest_cached_path(valves,
+                                                   distances,
+                                                   memo,
+                                                   remaining_minutes,
+                                                   dest_idx,
+                                                   &remaining_to_visit);
+            }
+
+            best = max(best, score);
+        }
+
+        best
+    })
+}
+
+fn part1(valves: &Vec<Valve>) -> u64 {
+    let distances = find_distances(valves);
+    let start_idx = valves.iter().enumerate()
+        .find(|(_, v)| v.name == "AA")
+        .unwrap().0;
+    let to_visit = valves.iter().enumerate()
+        .filter(|(_, v)| v.flow_rate > 0)
+        .map(|(idx, _)| idx)
+        .collect_vec();
+    let mut memo = Memo::new("valves");
+
+    find_shortest_cached_path(valves,
+                              &distances,
+                              &mut memo,
+                              30,
+                              start_idx,
+                              &to_visit)
+}
+
+fn part2(valves: &Vec<Valve>) -> u64 {
+    let distances = find_distances(valves);
+    let start_idx = valves.iter().enumerate()
+        .find(|(_, v)| v.name == "AA")
+        .unwrap().0;
+    let to_visit = valves.iter().enumerate()
+        .filter(|(_, v)| v.flow_rate > 0)
+        .map(|(idx, _)| idx)
+        .collect_vec();
+    let routes_a = to_visit.iter().cloned().powerset()
+        .filter(|route_a| route_a.len() <= to_visit.len() / 2)
+        .collect_vec();
+
+    // each thread keeps its own memo for the splits it takes on
+    parallel::map_with(&routes_a, || Memo::new("valves"), |memo, route_a| {
+        let route_b = to_visit.iter()
+            .filter(|i| !route_a.contains(i))
+            .cloned()
+            .collect_vec();
+        [route_a, &route_b].iter()
+            .map(|route| find_shortest_cached_path(valves,
+                                                   &distances,
+                                                   memo,
+                                                   26,
+                                                   start_idx,
+                                                   route))
+            .sum::<u64>()
+    }).into_iter().max().unwrap_or(0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Valve>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Valve>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Valve>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Valve>) -> u64 {
+        part2(input)
+    }
+}
+
+impl Generator for Day16 {
+    // counted in valves, at most 64 as they are kept in the bits of a number
+    const SIZE: usize = 60;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let num_valves = size.clamp(2, 64);
+        let mut names = vec!("AA".to_string());
+        while names.len() < num_valves {
+            let name = (0..2).map(|_| (b'A' + rng.below(26) as u8) as char).collect::<String>();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        // a tree connects all valves, a few more tunnels make shortcuts
+        let mut tunnels = vec![vec!(); num_valves];
+        let mut connect = |a: usize, b: usize| if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        };
+        for valve in 1..num_valves {
+            connect(valve, rng.below(valve));
+        }
+        for _ in 0..num_valves / 4 {
+            connect(rng.below(num_valves), rng.below(num_valves));
+        }
+
+        // like the real inputs, about a quarter of the valves (and not AA) release pressure
+        let mut flowing = (1..num_valves).collect::<Vec<_>>();
+        rng.shuffle(&mut flowing);
+        flowing.truncate((num_valves / 4).clamp(1, 15));
+
+        let mut input = String::new();
+        for (valve, name) in names.iter().enumerate() {
+            let rate = if flowing.contains(&valve) { rng.range(3..=25) } else { 0 };
+            let to = tunnels[valve].iter().map(|&other| names[other].as_str()).collect::<Vec<_>>();
+            let lead = if to.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+            input += &format!("Valve {name} has flow rate={rate}; {lead} {}\n", to.join(", "));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day16>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day16>(20, &[1, 2]);
+    }
+}
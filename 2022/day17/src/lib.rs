@@ -0,0 +1,259 @@
+use aoc_common::cycle::History;
+use aoc_common::visualize::{self, Color, Frame, Pixel, Render};
+use aoc_common::{end_of_input, lines, Answer, Generator, Grid, ParseError, Reference, Rng, Solution};
+
+use crate::Shape::*;
+
+const CHAMBER_WIDTH: usize = 7;
+
+// the rows of the tower shown when visualizing, from the top
+const VIEW_HEIGHT: usize = 30;
+
+// deeper than a falling rock gets below the top of the tower in practice
+const SURFACE_DEPTH: usize = 64;
+
+//                  #
+//  #           #   #
+// ###          #   #  ##
+//  #  ####   ###   #  ##
+#[derive(Debug)]
+enum Shape {
+    Cross,
+    Horizontal,
+    ReversedL,
+    Square,
+    Vertical,
+}
+
+impl Shape {
+    // (row, col) offsets from the bottom left corner, rows count upwards
+    fn cells(&self) -> &'static [(isize, isize)] {
+        match *self {
+            Cross => &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+            Horizontal => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            ReversedL => &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+            Square => &[(0, 0), (0, 1), (1, 0), (1, 1)],
+            Vertical => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rock {
+    shape: Shape,
+    pos: (isize, isize),
+}
+
+impl Rock {
+    fn cells(&self) -> impl Iterator<Item=(isize, isize)> + '_ {
+        self.shape.cells().iter().map(|cell| (self.pos.0 + cell.0, self.pos.1 + cell.1))
+    }
+
+    // the chamber holds the rows from the floor up to the highest rock, everything above is free
+    fn fits(&self, chamber: &Grid<bool>) -> bool {
+        self.cells().all(|cell| cell.0 >= 0 && (0..CHAMBER_WIDTH as isize).contains(&cell.1)
+            && chamber.get(cell) != Some(&true))
+    }
+}
+
+struct Tower<'a>(&'a Grid<bool>);
+
+impl Render for Tower<'_> {
+    // the top of the tower between the walls, the floor shows up while it is low
+    fn render(&self) -> Frame {
+        let chamber = self.0;
+        let top = chamber.height().max(VIEW_HEIGHT - 1) as isize - 1;
+        let mut frame = Grid::new(CHAMBER_WIDTH + 2, VIEW_HEIGHT, Pixel::new('|', Color::GREY));
+        for row in 0..VIEW_HEIGHT as isize {
+            for col in 0..CHAMBER_WIDTH as isize {
+                frame[(row, col + 1)] = if top - row < 0 {
+                    Pixel::new('-', Color::GREY)
+                } else if chamber.get((top - row, col)) == Some(&true) {
+                    Pixel::new('#', Color::CYAN)
+                } else {
+                    Pixel::new('.', Color::GREY)
+                };
+            }
+        }
+        frame
+    }
+}
+
+fn nth_falling_in_sequence(nth: u64) -> Shape {
+    match (nth - 1) % 5 {
+        0 => Horizontal,
+        1 => Cross,
+        2 => ReversedL,
+        3 => Vertical,
+        4 => Square,
+        _ => panic!()
+    }
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<u8>, ParseError> {
+    let Some(row) = lines(rows).next().filter(|row| !row.text.is_empty()) else {
+        return Err(end_of_input(rows).error("expected a line of jets"));
+    };
+    if let Some(jet) = row.chars().find(|jet| jet.text != "<" && jet.text != ">") {
+        return Err(jet.error("expected < or >"));
+    }
+    Ok(row.text.as_bytes().to_vec())
+}
+
+fn simulate_nth_rock(chamber: &mut Grid<bool>, jets: &[u8], jet_idx: &mut usize, nth_rock: u64) {
+    let mut rock = Rock {
+        shape: nth_falling_in_sequence(nth_rock),
+        pos: (chamber.height() as isize + 3, 2),
+    };
+
+    loop {
+        // push by jet
+        let push = if jets[*jet_idx] == b'<' { -1 } else { 1 };
+        rock.pos.1 += push;
+        if !rock.fits(chamber) {
+            rock.pos.1 -= push;
+        }
+        *jet_idx = (*jet_idx + 1) % jets.len();
+
+        // fall down
+        rock.pos.0 -= 1;
+        if !rock.fits(chamber) {
+            rock.pos.0 += 1;
+            break;
+        }
+    }
+
+    for cell in rock.cells() {
+        while chamber.height() as isize <= cell.0 {
+            chamber.push_row([false; CHAMBER_WIDTH]);
+        }
+        chamber[cell] = true;
+    }
+    visualize::show(&Tower(chamber));
+}
+
+// the free cells a falling rock could get to from above, a row of bits for each depth below the top of the
+// tower, rocks slide under overhangs so the top of each column isn't enough; a column which stays empty
+// (e.g. when the jets all push one way) would keep the tower from repeating if the depth wasn't capped
+fn surface(chamber: &Grid<bool>) -> [u8; SURFACE_DEPTH] {
+    let top = chamber.height() as isize - 1;
+    let mut reachable = [0u8; SURFACE_DEPTH];
+    let mut todo = (0..CHAMBER_WIDTH as isize).map(|col| (top, col)).collect::<Vec<_>>();
+    while let Some((row, col)) = todo.pop() {
+        if row < 0 || top - row >= SURFACE_DEPTH as isize || !(0..CHAMBER_WIDTH as isize).contains(&col)
+            || chamber[(row, col)] || reachable[(top - row) as usize] & 1 << col != 0 {
+            continue;
+        }
+        reachable[(top - row) as usize] |= 1 << col;
+        todo.extend([(row - 1, col), (row, col - 1), (row, col + 1)]);
+    }
+    reachable
+}
+
+// every rock falls in turn
+fn simulated_height(jets: &[u8], rocks: u64) -> u64 {
+    let mut jet_idx = 0usize;
+    let mut chamber = Grid::new(CHAMBER_WIDTH, 0, false);
+
+    for nth_rock in 1..=rocks {
+        simulate_nth_rock(&mut chamber, jets, &mut jet_idx, nth_rock);
+    }
+
+    chamber.height() as u64
+}
+
+// the rocks fall until the tower grows the same way over and over
+fn extrapolated_height(jets: &[u8], rocks: u64) -> u64 {
+    let mut jet_idx = 0usize;
+    let mut chamber = Grid::new(CHAMBER_WIDTH, 0, false);
+    let mut history = History::new();
+    let mut nth_rock = 0;
+
+    // the next rock and jet and the surface they fall onto decide how the tower grows from here on
+    let cycle = loop {
+        if let Some(cycle) = history.push((nth_rock % 5, jet_idx, surface(&chamber)), chamber.height() as i64) {
+            break cycle;
+        }
+        nth_rock += 1;
+        simulate_nth_rock(&mut chamber, jets, &mut jet_idx, nth_rock);
+    };
+
+    cycle.extrapolate(history.values(), rocks) as u64
+}
+
+fn part1(jets: &[u8]) -> u64 {
+    simulated_height(jets, 2022)
+}
+
+fn part2(jets: &[u8]) -> u64 {
+    extrapolated_height(jets, 1000000000000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<u8>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<u8>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<u8>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<u8>) -> u64 {
+        part2(input)
+    }
+}
+
+impl Generator for Day17 {
+    // counted in jets
+    const SIZE: usize = 10091;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| if rng.chance(0.5) { '<' } else { '>' }).collect::<String>() + "\n"
+    }
+}
+
+// as many rocks as the tower of a short line of jets takes to repeat a few times
+const REFERENCE_ROCKS: u64 = 5000;
+
+impl Reference for Day17 {
+    const PARTS: &'static [u8] = &[2];
+    const REFERENCE_SIZE: usize = 40;
+
+    fn clever(jets: &Vec<u8>, _: u8) -> Answer {
+        extrapolated_height(jets, REFERENCE_ROCKS).into()
+    }
+
+    fn naive(jets: &Vec<u8>, _: u8) -> Answer {
+        simulated_height(jets, REFERENCE_ROCKS).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+    use aoc_common::reference::check_reference;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day17>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day17>(Day17::SIZE, &[1, 2]);
+    }
+
+    #[test]
+    fn naive_parts() {
+        check_reference::<Day17>(Day17::REFERENCE_SIZE, 1..=20);
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use aoc_common::{lines, Generator, ParseError, Point3, Rng, Solution};
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Point3>, ParseError> {
+    lines(rows)
+        .map(|row| row.split(','))
+        .map(|tokens| Ok(Point3::new(tokens.parse(0)?, tokens.parse(1)?, tokens.parse(2)?)))
+        .collect()
+}
+
+fn count_uncovered_sides<P>(cubes: &Vec<Point3>, predicate: P) -> u64
+    where P: Fn(&Point3) -> bool
+{
+    cubes.iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|side| predicate(side))
+        .count() as u64
+}
+
+fn part1(cubes: &Vec<Point3>) -> u64 {
+    let droplets: HashSet<Point3> = cubes.iter().copied().collect();
+    count_uncovered_sides(cubes, |pos| !droplets.contains(pos))
+}
+
+fn part2(cubes: &Vec<Point3>) -> u64 {
+    let droplets: HashSet<Point3> = cubes.iter().copied().collect();
+
+    // the steam surrounds the droplets, starting from a corner of a box one larger than them
+    let (min, max) = Point3::bounds(cubes.iter().copied()).unwrap();
+    let (min, max) = (min - Point3::new(1, 1, 1), max + Point3::new(1, 1, 1));
+    let within = |p: &Point3| (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y) && (min.z..=max.z).contains(&p.z);
+
+    let mut steam: HashSet<Point3> = HashSet::from([min]);
+    let mut todo = vec!(min);
+    while let Some(pos) = todo.pop() {
+        for next in pos.neighbours() {
+            if within(&next) && !droplets.contains(&next) && steam.insert(next) {
+                todo.push(next);
+            }
+        }
+    }
+
+    count_uncovered_sides(cubes, |pos| steam.contains(pos))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Point3>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Point3>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Point3>) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Point3>) -> u64 {
+        part2(input)
+    }
+}
+
+impl Generator for Day18 {
+    // counted in cubes
+    const SIZE: usize = 2800;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        // filling about a third of a box, which leaves air pockets
+        let size = size.max(1);
+        let side = ((size * 3) as f64).cbrt().ceil() as i64;
+        let mut cubes = (0..side).flat_map(|x| (0..side).flat_map(move |y| (0..side).map(move |z| (x, y, z))))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut cubes);
+        cubes.truncate(size);
+        cubes.iter().map(|(x, y, z)| format!("{x},{y},{z}\n")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day18>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day18>(Day18::SIZE, &[1, 2]);
+    }
+}
@@ -1,18 +1,12 @@
 use std::cmp::max;
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn readlines(filename: &str) -> Vec<String> {
-    let file = BufReader::new(File::open(&filename)
-        .expect(format!("File \"{filename}\" not found").as_ref()));
-    return file.lines().map(Result::unwrap).collect();
-}
+
+use aoc_common::parallel;
+use aoc_common::{lines, Generator, Memo, ParseError, Rng, Solution};
 
 type Costs = [u32; 3];
 type Blueprint = [Costs; 4];
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 struct Inventory {
     num_robots: [u32; 4],
     resources: [u32; 4],
@@ -26,33 +20,55 @@ impl Inventory {
     }
 }
 
-fn parse(rows: &Vec<String>) -> Vec<Blueprint> {
+fn parse(rows: &Vec<String>) -> Result<Vec<Blueprint>, ParseError> {
     let mut blueprints: Vec<Blueprint> = vec!();
 
-    for row in rows {
-        let token = row.split(' ').collect::<Vec<_>>();
+    for row in lines(rows) {
+        let token = row.split(' ');
+        token.get(0)?.expect("Blueprint")?;
         blueprints.push([
-            [token[6].parse().unwrap(), 0, 0],
-            [token[12].parse().unwrap(), 0, 0],
-            [token[18].parse().unwrap(), token[21].parse().unwrap(), 0],
-            [token[27].parse().unwrap(), 0, token[30].parse().unwrap()],
+            [token.parse(6)?, 0, 0],
+            [token.parse(12)?, 0, 0],
+            [token.parse(18)?, token.parse(21)?, 0],
+            [token.parse(27)?, 0, token.parse(30)?],
         ]);
     }
 
-    blueprints
+    Ok(blueprints)
 }
 
+// the same inventories are reached by building the robots in a different order
+type States = Memo<(Inventory, u32, i32, i32), u32>;
+
+const MEMO_MIN_MINUTES: u32 = 6;
+
 fn find_max_geodes_rek(blueprint: &Blueprint,
+                       memo: &mut States,
                        inventory: &Inventory,
                        remaining_minutes: u32,
                        buildable_over_time: i32,
                        not_to_build: i32) -> u32 {
-    let mut best = *inventory.resources.last().unwrap();
-
     if remaining_minutes == 0 {
-        return best;
+        return *inventory.resources.last().unwrap();
+    }
+    // the last minutes are quicker to search again than to look up
+    if remaining_minutes < MEMO_MIN_MINUTES {
+        return find_max_geodes_uncached(blueprint, memo, inventory, remaining_minutes, buildable_over_time, not_to_build);
     }
 
+    let key = (inventory.clone(), remaining_minutes, buildable_over_time, not_to_build);
+    memo.get_or_compute(key, |memo| find_max_geodes_uncached(blueprint, memo, inventory, remaining_minutes,
+                                                             buildable_over_time, not_to_build))
+}
+
+fn find_max_geodes_uncached(blueprint: &Blueprint,
+                            memo: &mut States,
+                            inventory: &Inventory,
+                            remaining_minutes: u32,
+                            buildable_over_time: i32,
+                            not_to_build: i32) -> u32 {
+    let mut best = *inventory.resources.last().unwrap();
+
     let buildable_with_current_resources = blueprint.iter().enumerate()
         .filter(|(_, costs)| costs.iter().enumerate()
             .all(|(res_idx, &costs)| inventory.resources[res_idx] >= costs))
@@ -66,6 +82,7 @@ fn find_max_geodes_rek(blueprint: &Blueprint,
     // try without buying anything at all
     if buildable_over_time != buildable_with_current_resources {
         best = max(best, find_max_geodes_rek(blueprint,
+                                             memo,
                                              inventory.clone().collect_current_resources(),
                                              remaining_minutes - 1,
                                              buildable_over_time,
@@ -99,6 +116,7 @@ fn find_max_geodes_rek(blueprint: &Blueprint,
         };
 
         best = max(best, find_max_geodes_rek(blueprint,
+                                             memo,
                                              &new_inventory,
                                              remaining_minutes - 1,
                                              buildable_over_time,
@@ -117,51 +135,84 @@ fn find_max_geodes(blueprint: &Blueprint,
         .map(|(res_idx, _)| res_idx)
         .fold(0, |a, b| a | (1 << b));
 
-    find_max_geodes_rek(blueprint, inventory, remaining_minutes, buildable_over_time, 0)
+    // bounded to keep the memory in check on the longer runs of part 2
+    let mut memo = Memo::bounded("inventories", 1 << 22);
+    find_max_geodes_rek(blueprint, &mut memo, inventory, remaining_minutes, buildable_over_time, 0)
 }
 
-fn part1(rows: &Vec<String>) -> u32 {
-    let blueprints = parse(rows);
+fn part1(blueprints: &Vec<Blueprint>) -> u32 {
     let inventory = Inventory {
         num_robots: [1, 0, 0, 0],
         resources: [0; 4],
     };
     let remaining_minutes = 24u32;
 
-    blueprints.iter().enumerate()
-        .map(|(idx, blueprint)| find_max_geodes(blueprint, &inventory, remaining_minutes) * (idx as u32 + 1))
+    parallel::map(blueprints, |blueprint| find_max_geodes(blueprint, &inventory, remaining_minutes))
+        .iter().enumerate()
+        .map(|(idx, geodes)| geodes * (idx as u32 + 1))
         .sum()
 }
 
-fn part2(rows: &Vec<String>) -> u32 {
-    let blueprints = parse(rows);
+fn part2(blueprints: &Vec<Blueprint>) -> u32 {
     let inventory = Inventory {
         num_robots: [1, 0, 0, 0],
         resources: [0; 4],
     };
     let remaining_minutes = 32u32;
 
-    blueprints.iter().take(3).enumerate()
-        .map(|(idx, blueprint)| find_max_geodes(blueprint, &inventory, remaining_minutes))
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    parallel::map(blueprints, |blueprint| find_max_geodes(blueprint, &inventory, remaining_minutes))
+        .iter()
         .product()
 }
 
-fn main() {
-    let args = env::args().collect::<Vec<_>>();
-    let filename = if args.len() == 2 { &args[1] } else { "input" };
-    let lines = readlines(filename);
-    println!("Part 1: {}", part1(&lines));
-    println!("Part 2: {}", part2(&lines));
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Blueprint>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Blueprint>) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Blueprint>) -> u32 {
+        part2(input)
+    }
+}
+
+impl Generator for Day19 {
+    // counted in blueprints
+    const SIZE: usize = 30;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        (1..=size.max(1))
+            .map(|id| format!("Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                               Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                              rng.range(2..=4), rng.range(2..=4), rng.range(2..=4), rng.range(5..=20), rng.range(2..=4), rng.range(5..=20)))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
     use super::*;
 
     #[test]
-    fn it_works() {
-        let lines = readlines("test.in");
-        assert_eq!(part1(&lines), 33);
-        assert_eq!(part2(&lines), 56*62);
+    fn examples() {
+        check_examples::<Day19>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day19>(1, &[1, 2]);
     }
 }
@@ -0,0 +1,157 @@
+use aoc_common::{end_of_input, lines, Answer, Generator, ParseError, Reference, Rng, Solution};
+
+struct Node {
+    number: i64,
+    order: usize,
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<i64>, ParseError> {
+    let numbers = lines(rows).map(|row| row.parse()).collect::<Result<Vec<i64>, _>>()?;
+    if !numbers.contains(&0) {
+        return Err(end_of_input(rows).error("expected a 0 to find the grove coordinates from"));
+    }
+    Ok(numbers)
+}
+
+fn to_nodes(numbers: &[i64]) -> Vec<Node> {
+    numbers.iter().enumerate().map(|(order, &number)| Node {
+        number,
+        order
+    }).collect::<Vec<_>>()
+}
+
+fn mix(numbers: &mut Vec<Node>) {
+    for order in 0..numbers.len() {
+        let mut idx = numbers.iter().position(|n| n.order == order).unwrap();
+        let number = numbers[idx].number;
+
+        let mut num_steps = number.abs() as usize;
+        while num_steps >= numbers.len() {
+            num_steps = num_steps / numbers.len() + num_steps % numbers.len();
+        }
+
+        for _ in 0..num_steps {
+            let idx_b = if number >= 0 { idx + 1 } else { idx + numbers.len() - 1 } % numbers.len();
+            numbers.swap(idx, idx_b);
+            idx = idx_b;
+        }
+    }
+}
+
+// every number moved one step at a time, all the way
+fn mix_step_by_step(numbers: &mut Vec<Node>) {
+    for order in 0..numbers.len() {
+        let mut idx = numbers.iter().position(|n| n.order == order).unwrap();
+        let number = numbers[idx].number;
+
+        for _ in 0..number.unsigned_abs() {
+            let idx_b = if number >= 0 { idx + 1 } else { idx + numbers.len() - 1 } % numbers.len();
+            numbers.swap(idx, idx_b);
+            idx = idx_b;
+        }
+    }
+}
+
+fn sum_grove_coordinates(nodes: &Vec<Node>) -> i64 {
+    let zero_idx = nodes.iter().position(|n| n.number == 0).unwrap();
+    nodes[(zero_idx + 1000) % nodes.len()].number
+        + nodes[(zero_idx + 2000) % nodes.len()].number
+        + nodes[(zero_idx + 3000) % nodes.len()].number
+}
+
+fn decrypt(numbers: &Vec<i64>, key: i64, rounds: usize, mix: fn(&mut Vec<Node>)) -> i64 {
+    let mut nodes = to_nodes(numbers);
+    nodes.iter_mut().for_each(|n| n.number *= key);
+
+    (0..rounds).for_each(|_| mix(&mut nodes));
+    sum_grove_coordinates(&nodes)
+}
+
+fn part1(numbers: &Vec<i64>) -> i64 {
+    decrypt(numbers, 1, 1, mix)
+}
+
+fn part2(numbers: &Vec<i64>) -> i64 {
+    decrypt(numbers, 811589153, 10, mix)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<i64>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<i64>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> i64 {
+        part2(input)
+    }
+}
+
+impl Generator for Day20 {
+    // counted in numbers
+    const SIZE: usize = 5000;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        // a single 0 to find the grove coordinates from
+        let mut numbers = (1..size.max(1)).map(|_| {
+            let number = rng.range(1..=10000);
+            if rng.chance(0.5) { -number } else { number }
+        }).collect::<Vec<_>>();
+        numbers.insert(rng.below(numbers.len() + 1), 0);
+        numbers.iter().map(|number| format!("{number}\n")).collect()
+    }
+}
+
+// small enough to move the numbers step by step, but still far around the circle
+const REFERENCE_KEY: i64 = 7;
+
+impl Reference for Day20 {
+    const PARTS: &'static [u8] = &[1, 2];
+    const REFERENCE_SIZE: usize = 30;
+
+    fn clever(numbers: &Vec<i64>, part: u8) -> Answer {
+        match part {
+            1 => part1(numbers).into(),
+            _ => decrypt(numbers, REFERENCE_KEY, 10, mix).into(),
+        }
+    }
+
+    fn naive(numbers: &Vec<i64>, part: u8) -> Answer {
+        match part {
+            1 => decrypt(numbers, 1, 1, mix_step_by_step).into(),
+            _ => decrypt(numbers, REFERENCE_KEY, 10, mix_step_by_step).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+    use aoc_common::reference::check_reference;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day20>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day20>(1000, &[1, 2]);
+    }
+
+    #[test]
+    fn naive_parts() {
+        check_reference::<Day20>(Day20::REFERENCE_SIZE, 1..=20);
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{end_of_input, lines, Answer, Generator, ParseError, Reference, Rng, Solution};
+
+use crate::Job::*;
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    Yell(i64),
+    Op(String, String, u8),
+}
+
+fn parse(rows: &Vec<String>) -> Result<HashMap<String, Job>, ParseError> {
+    let monkeys = lines(rows).map(|line| {
+        let (name, rhs) = line.split_once(": ")?;
+        let rhs_token = rhs.split(' ');
+        if rhs_token.len() == 1 {
+            Ok((name.text.to_string(), Yell(rhs_token.parse(0)?)))
+        } else {
+            let op = rhs_token.get(1)?;
+            if !["+", "-", "*", "/"].contains(&op.text) {
+                return Err(op.error("expected +, -, * or /"));
+            }
+            Ok((name.text.to_string(), Op(rhs_token.get(0)?.text.to_string(),
+                                          rhs_token.get(2)?.text.to_string(),
+                                          op.text.as_bytes()[0])))
+        }
+    }).collect::<Result<HashMap<_, _>, _>>()?;
+
+    if !matches!(monkeys.get("root"), Some(Op(_, _, _))) {
+        return Err(end_of_input(rows).error("expected a root monkey waiting for two others"));
+    }
+
+    Ok(monkeys)
+}
+
+fn process_until_unchanged(monkeys: &mut HashMap<String, Job>) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for to_check in monkeys.iter()
+            .filter(|(_, job)| matches!(job, Op(_, _, _)))
+            .map(|(name, _)| name)
+            .cloned()
+            .collect::<Vec<_>>()
+        {
+            let Op(lhs, rhs, op) = monkeys.get(&to_check).unwrap() else { panic!() };
+
+            let left = if let Some(Yell(number)) = monkeys.get(lhs) { Some(number) } else { None };
+            let right = if let Some(Yell(number)) = monkeys.get(rhs) { Some(number) } else { None };
+
+            if let Some(result) = left.zip(right).and_then(|(l, &r)| match op {
+                b'+' => l.checked_add(r),
+                b'-' => l.checked_sub(r),
+                b'*' => l.checked_mul(r),
+                b'/' => l.checked_div(r),
+                _ => panic!()
+            }) {
+                monkeys.insert(to_check, Yell(result));
+                changed = true;
+            }
+        }
+    }
+}
+
+fn find_diff_for_yell(monkeys: &HashMap<String, Job>, to_yell: i64) -> Option<i64> {
+    let mut monkeys = monkeys.clone();
+    let Op(root_lhs, root_rhs, _) = monkeys.get("root").cloned().unwrap() else { panic!() };
+    monkeys.insert("humn".to_string(), Yell(to_yell));
+    process_until_unchanged(&mut monkeys);
+
+    monkeys.get(&root_lhs).zip(monkeys.get(&root_rhs)).and_then(|jobs| match jobs {
+        (Yell(left), Yell(right)) => Some(left - right),
+        _ => None
+    })
+}
+
+// the difference at root either grows or shrinks with what humn yells
+fn find_it(monkeys: &HashMap<String, Job>,
+           lower_bound: i64,
+           upper_bound: i64,
+           growing: bool) -> Option<i64> {
+    if lower_bound > upper_bound {
+        return None;
+    }
+    let half = lower_bound + (upper_bound - lower_bound) / 2;
+
+    if let Some(diff) = find_diff_for_yell(monkeys, half) {
+        return if diff == 0 {
+            Some(half)
+        } else if (diff < 0) == growing {
+            find_it(monkeys, half + 1, upper_bound, growing)
+        } else {
+            find_it(monkeys, lower_bound, half - 1, growing)
+        }
+    }
+
+    None
+}
+
+// the bounds to search between and whether the difference at root grows between them
+fn find_bounds(monkeys: &HashMap<String, Job>) -> (i64, i64, bool) {
+    let lower_limit = 0i64;
+    let mut upper_limit = i64::MAX;
+
+    let lower = find_diff_for_yell(monkeys, lower_limit);
+
+    let mut upper = find_diff_for_yell(monkeys, upper_limit);
+    let mut shift = 1;
+    while upper.is_none() {
+        upper_limit = i64::MAX >> shift;
+        shift += 1;
+        upper = find_diff_for_yell(monkeys, upper_limit);
+    }
+
+    (lower_limit, upper_limit, lower.unwrap() <= upper.unwrap())
+}
+
+fn part1(monkeys: &HashMap<String, Job>) -> i64 {
+    let mut monkeys = monkeys.clone();
+    process_until_unchanged(&mut monkeys);
+
+    if let Some(Yell(number)) = monkeys.get("root") {
+        return *number;
+    } else {
+        panic!("Didn't find a solution");
+    }
+}
+
+// the monkeys which don't wait for humn have yelled their numbers already
+fn waiting_for_humn(monkeys: &HashMap<String, Job>) -> HashMap<String, Job> {
+    let mut monkeys = monkeys.clone();
+    monkeys.insert("humn".to_string(),Op("not existing".to_string(),
+                                         "not existing".to_string(), b'+'));
+    process_until_unchanged(&mut monkeys);
+    monkeys
+}
+
+fn part2(monkeys: &HashMap<String, Job>) -> i64 {
+    let monkeys = waiting_for_humn(monkeys);
+
+    let (lower_bound, upper_bound, growing) = find_bounds(&monkeys);
+
+    if let Some(mut result) = find_it(&monkeys, lower_bound, upper_bound, growing) {
+        while let Some(0) = find_diff_for_yell(&monkeys, result - 1) {
+            result -= 1;
+        }
+        return result;
+    }
+
+    panic!("Didn't find a solution");
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Job>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(rows: &Vec<String>) -> Result<HashMap<String, Job>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &HashMap<String, Job>) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &HashMap<String, Job>) -> i64 {
+        part2(input)
+    }
+}
+
+// made up monkeys, each with a name not taken yet
+struct Troop {
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop {
+    fn add(&mut self, rng: &mut Rng, job: String) -> String {
+        let name = loop {
+            let name = (0..4).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+            if self.names.insert(name.clone()) {
+                break name;
+            }
+        };
+        self.jobs.push(format!("{name}: {job}"));
+        name
+    }
+
+    // a monkey yelling the number in the end, with about the given number of monkeys involved
+    fn yell(&mut self, rng: &mut Rng, number: i64, monkeys: usize) -> String {
+        if monkeys < 3 {
+            return self.add(rng, number.to_string());
+        }
+        let left_monkeys = 1 + rng.below(monkeys - 2);
+        let right_monkeys = monkeys - 1 - left_monkeys;
+
+        // the divisions come out even, like in the real inputs
+        let factor = rng.range(2..=5);
+        let (left, op, right) = if number % factor == 0 && rng.chance(0.3) {
+            (number / factor, '*', factor)
+        } else if number.abs() < 1_000_000_000_000 && rng.chance(0.2) {
+            (number * factor, '/', factor)
+        } else if number > 1 && rng.chance(0.5) {
+            let left = rng.range(1..=number - 1);
+            (left, '+', number - left)
+        } else {
+            let right = rng.range(1..=1000);
+            (number + right, '-', right)
+        };
+        let left = self.yell(rng, left, left_monkeys);
+        let right = self.yell(rng, right, right_monkeys);
+        self.add(rng, format!("{left} {op} {right}"))
+    }
+}
+
+// about the given number of monkeys, humn has to yell a number up to the limit
+fn made_up_monkeys(rng: &mut Rng, size: usize, limit: i64) -> String {
+    let mut troop = Troop { names: HashSet::from(["root".to_string(), "humn".to_string()]), jobs: vec!() };
+    let steps = (size / 30).max(1);
+    let monkeys = size.saturating_sub(2 + 2 * steps) / (steps + 1);
+
+    // what humn has to yell, the monkeys waiting for humn are made up from there on, so
+    // that their numbers can't overflow while searching for it
+    let mut number = rng.range(1..=limit);
+    let mut waiting = "humn".to_string();
+    for _ in 0..steps {
+        let factor = rng.range(2..=10);
+        let job = if number.abs() < 1_000_000_000_000 && rng.chance(0.3) {
+            number *= factor;
+            let other = troop.yell(rng, factor, monkeys);
+            if rng.chance(0.5) { format!("{waiting} * {other}") } else { format!("{other} * {waiting}") }
+        } else if number % factor == 0 && rng.chance(0.3) {
+            number /= factor;
+            format!("{waiting} / {}", troop.yell(rng, factor, monkeys))
+        } else {
+            let summand = rng.range(1..=1000);
+            let other = troop.yell(rng, summand, monkeys);
+            match rng.below(3) {
+                0 => {
+                    number += summand;
+                    format!("{waiting} + {other}")
+                }
+                1 => {
+                    number -= summand;
+                    format!("{waiting} - {other}")
+                }
+                _ => {
+                    number = summand - number;
+                    format!("{other} - {waiting}")
+                }
+            }
+        };
+        waiting = troop.add(rng, job);
+    }
+
+    let other = troop.yell(rng, number, monkeys);
+    let root = if rng.chance(0.5) { format!("root: {waiting} + {other}") } else { format!("root: {other} + {waiting}") };
+    troop.jobs.push(root);
+    troop.jobs.push(format!("humn: {}", rng.range(1..=5000)));
+    rng.shuffle(&mut troop.jobs);
+    troop.jobs.iter().map(|job| format!("{job}\n")).collect()
+}
+
+impl Generator for Day21 {
+    // counted in monkeys
+    const SIZE: usize = 2000;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        made_up_monkeys(rng, size, 1_000_000_000_000)
+    }
+}
+
+// how far the naive part 2 counts, far beyond the numbers humn yells in the reference inputs
+const REFERENCE_LIMIT: i64 = 2000;
+
+impl Reference for Day21 {
+    const PARTS: &'static [u8] = &[2];
+    const REFERENCE_SIZE: usize = 150;
+
+    fn reference_input(rng: &mut Rng, size: usize) -> String {
+        made_up_monkeys(rng, size, REFERENCE_LIMIT / 10)
+    }
+
+    // every number tried in turn, counting up from the lowest
+    fn naive(monkeys: &HashMap<String, Job>, _: u8) -> Answer {
+        let monkeys = waiting_for_humn(monkeys);
+        find_diff_for_yell(&monkeys, 0).expect("The monkeys at root get their numbers");
+        (-REFERENCE_LIMIT..=REFERENCE_LIMIT).find(|&number| find_diff_for_yell(&monkeys, number) == Some(0))
+            .expect("Didn't find a solution").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+    use aoc_common::reference::check_reference;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day21>("examples");
+    }
+
+    #[test]
+    fn finds_the_number_when_the_difference_shrinks() {
+        let rows = ["root: left + right", "left: sub - humn", "sub: 1000", "right: 40", "humn: 5"];
+        let monkeys = parse(&rows.iter().map(|row| row.to_string()).collect()).unwrap();
+        assert_eq!(part2(&monkeys), 960);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day21>(300, &[1, 2]);
+    }
+
+    #[test]
+    fn naive_parts() {
+        check_reference::<Day21>(Day21::REFERENCE_SIZE, 1..=20);
+    }
+}
@@ -0,0 +1,321 @@
+use aoc_common::{end_of_input, lines, Direction, Generator, Grid, ParseError, Rng, Solution, Token};
+
+use crate::Movement::*;
+
+#[derive(Clone, Debug)]
+pub enum Movement {
+    Forward(u8),
+    RotateClockwise,
+    RotateCounterclockwise,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Position {
+    row: isize,
+    col: isize,
+    facing: Direction,
+}
+
+type Warp = fn(grid: &Grid<u8>, &Position) -> Option<Position>;
+
+fn parse(rows: &Vec<String>) -> Result<(Grid<u8>, Vec<Movement>), ParseError> {
+    let lines = lines(rows).collect::<Vec<_>>();
+    let [map_lines @ .., separator, path] = lines.as_slice() else {
+        return Err(end_of_input(rows).error("expected a map and a path separated by a blank line"));
+    };
+    if !separator.text.is_empty() {
+        return Err(separator.error("expected a blank line between the map and the path"));
+    }
+    // the map is padded with spaces, which are off the map as well
+    let grid = Grid::parse_padded(map_lines.iter().copied(), b' ', |tile| match tile.text {
+        " " | "." | "#" => Ok(tile.text.as_bytes()[0]),
+        _ => Err(tile.error("expected one of \" \", \".\" or \"#\"")),
+    })?;
+    if !map_lines.first().is_some_and(|row| row.text.contains('.')) {
+        return Err(end_of_input(rows).error("expected an open tile in the first row of the map"));
+    }
+
+    let mut movements = vec!();
+    let mut digits_start: Option<Token> = None;
+    for c in path.chars() {
+        if c.text.as_bytes()[0].is_ascii_digit() {
+            digits_start.get_or_insert(c);
+            continue;
+        }
+        if let Some(start) = digits_start.take() {
+            movements.push(Forward(Token { text: &path.text[start.column - 1..c.column - 1], ..start }.parse()?));
+        }
+        movements.push(match c.text {
+            "R" => RotateClockwise,
+            "L" => RotateCounterclockwise,
+            _ => return Err(c.error("expected a number of steps, R or L")),
+        });
+    }
+    if let Some(start) = digits_start {
+        movements.push(Forward(Token { text: &path.text[start.column - 1..], ..start }.parse()?));
+    }
+
+    Ok((grid, movements))
+}
+
+// the row and the column to step to when facing into the direction
+fn step(pos: &Position) -> (isize, isize) {
+    let dir = pos.facing.vector();
+    (pos.row + dir.y as isize, pos.col + dir.x as isize)
+}
+
+fn password(pos: &Position) -> usize {
+    let facing = match pos.facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+    (1000 * (pos.row + 1) + 4 * (pos.col + 1)) as usize + facing
+}
+
+// the leftmost open tile of the top row
+fn start_col(grid: &Grid<u8>) -> isize {
+    grid.rows().next().unwrap().iter().position(|&c| c == b'.').unwrap() as isize
+}
+
+fn do_movement(grid: &Grid<u8>, pos: &Position, movement: &Movement, warp: Warp) -> Position {
+    match movement {
+        Forward(mut steps) => {
+            let mut last_pos = pos.clone();
+
+            while steps > 0 {
+                let new_pos = match warp(grid, &last_pos) {
+                    Some(p) => p,
+                    None => {
+                        let (row, col) = grid.wrap(step(&last_pos));
+                        Position { row, col, facing: last_pos.facing }
+                    }
+                };
+
+                if grid[(new_pos.row, new_pos.col)] == b'#' {
+                    break;
+                }
+
+                last_pos = new_pos;
+                steps -= 1;
+            }
+
+            last_pos
+        }
+        RotateClockwise => Position {
+            facing: pos.facing.turn_right(),
+            ..*pos
+        },
+        RotateCounterclockwise => Position {
+            facing: pos.facing.turn_left(),
+            ..*pos
+        }
+    }
+}
+
+fn part1((grid, movements): &(Grid<u8>, Vec<Movement>)) -> usize {
+
+    let mut pos = Position {
+        row: 0,
+        col: start_col(grid),
+        facing: Direction::Right,
+    };
+
+    for movement in movements {
+        pos = do_movement(grid, &pos, movement, |grid, pos| {
+            let mut new_pos = pos.clone();
+            loop {
+                (new_pos.row, new_pos.col) = grid.wrap(step(&new_pos));
+
+                if grid[(new_pos.row, new_pos.col)] != b' ' {
+                    break;
+                }
+            }
+
+            Some(new_pos)
+        });
+    }
+
+    password(&pos)
+}
+
+// the cube folding depends on the layout of the input, see wrap_input()
+fn part2((grid, movements): &(Grid<u8>, Vec<Movement>), warp: Warp) -> usize {
+
+    let mut pos = Position {
+        row: 0,
+        col: start_col(grid),
+        facing: Direction::Right,
+    };
+
+    for movement in movements {
+        pos = do_movement(grid, &pos, movement, warp);
+    }
+
+    password(&pos)
+}
+
+fn wrap_input(_: &Grid<u8>, pos: &Position) -> Option<Position> {
+    // Layout:
+    //   1 3
+    //   2
+    // 4 6
+    // 5
+    match (pos.row, pos.col, pos.facing) {
+        // 1 -> 4
+        (0..=49, 50, Direction::Left) => Some(Position { row: 149 - pos.row, col: 0, facing: Direction::Right }),
+        // 1 -> 5
+        (0, 50..=99, Direction::Up) => Some(Position { row: 100 + pos.col, col: 0, facing: Direction::Right }),
+
+        // 2 -> 3
+        (50..=99, 99, Direction::Right) => Some(Position { row: 49, col: 50 + pos.row, facing: Direction::Up }),
+        // 2 -> 4
+        (50..=99, 50, Direction::Left) => Some(Position { row: 100, col: pos.row - 50, facing: Direction::Down }),
+
+        // 3 -> 6
+        (0..=49, 149, Direction::Right) => Some(Position { row: 149 - pos.row, col: 99, facing: Direction::Left }),
+        // 3 -> 2
+        (49, 100..=149, Direction::Down) => Some(Position { row: pos.col - 50, col: 99, facing: Direction::Left }),
+        // 3 -> 5
+        (0, 100..=149, Direction::Up) => Some(Position { row: 199, col: pos.col - 100, facing: Direction::Up }),
+
+        // 4 -> 1
+        (100..=149, 0, Direction::Left) => Some(Position { row: 149 - pos.row, col: 50, facing: Direction::Right }),
+        // 4 -> 2
+        (100, 0..=49, Direction::Up) => Some(Position { row: 50 + pos.col, col: 50, facing: Direction::Right }),
+
+        // 5 -> 6
+        (150..=199, 49, Direction::Right) => Some(Position { row: 149, col: pos.row - 100, facing: Direction::Up }),
+        // 5 -> 3
+        (199, 0..=49, Direction::Down) => Some(Position { row: 0, col: pos.col + 100, facing: Direction::Down }),
+        // 5 -> 1
+        (150..=199, 0, Direction::Left) => Some(Position { row: 0, col: pos.row - 100, facing: Direction::Down }),
+
+        // 6 -> 3
+        (100..=149, 99, Direction::Right) => Some(Position { row: 149 - pos.row, col: 149, facing: Direction::Left }),
+        // 6 -> 5
+        (149, 50..=99, Direction::Down) => Some(Position { row: 100 + pos.col, col: 49, facing: Direction::Left }),
+
+        _ => None
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Grid<u8>, Vec<Movement>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // only folds inputs with the same layout as ours, see wrap_input()
+    const MANUAL_PARTS: &'static [u8] = &[2];
+
+    fn parse(rows: &Vec<String>) -> Result<(Grid<u8>, Vec<Movement>), ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &(Grid<u8>, Vec<Movement>)) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &(Grid<u8>, Vec<Movement>)) -> usize {
+        part2(input, wrap_input)
+    }
+}
+
+impl Generator for Day22 {
+    // counted in numbers of steps along the path, the map has the layout wrap_input() folds
+    const SIZE: usize = 2000;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        // the columns of the faces within each band of 50 rows
+        let bands = [50..150, 50..100, 0..100, 0..50];
+        let mut input = String::new();
+        for (row, cols) in bands.iter().flat_map(|cols| (0..50).map(move |_| cols)).enumerate() {
+            input += &" ".repeat(cols.start);
+            for col in cols.clone() {
+                // the path starts on the leftmost tile of the top row
+                let wall = (row, col) != (0, 50) && rng.chance(0.1);
+                input.push(if wall { '#' } else { '.' });
+            }
+            input.push('\n');
+        }
+
+        input.push('\n');
+        for idx in 0..size.max(1) {
+            if idx > 0 {
+                input.push(if rng.chance(0.5) { 'R' } else { 'L' });
+            }
+            input += &rng.range(1..=50).to_string();
+        }
+        input + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::check_generator;
+    use aoc_common::{check_examples, readlines};
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day22>("examples");
+    }
+
+    #[test]
+    fn folds_the_example() {
+        let notes = parse(&readlines("examples/test.in").unwrap()).unwrap();
+        assert_eq!(part2(&notes, |_, pos| {
+            // layout:
+            //     1
+            // 5 4 2
+            //     6 3
+
+            match (pos.row, pos.col, pos.facing) {
+                // 1 -> 3
+                (0..=3, 11, Direction::Right) => Some(Position { row: 11 - pos.row, col: 15, facing: Direction::Left }),
+                // 1 -> 4
+                (0..=3, 8, Direction::Left) => Some(Position { row: 4, col: 4 + pos.row, facing: Direction::Down }),
+                // 1 -> 5
+                (0, 8..=11, Direction::Up) => Some(Position { row: 4, col: 11 - pos.col, facing: Direction::Down }),
+
+                // 2 -> 3
+                (4..=7, 11, Direction::Right) => Some(Position { row: 8, col: 15 - pos.row % 4, facing: Direction::Down }),
+
+                // 3 -> 1
+                (8..=11, 15, Direction::Right) => Some(Position { row: 11 - pos.row, col: 11, facing: Direction::Left }),
+                // 3 -> 5
+                (11, 12..=15, Direction::Down) => Some(Position { row: 7 - pos.col % 4, col: 0, facing: Direction::Right }),
+                // 3 -> 2
+                (8, 12..=15, Direction::Up) => Some(Position { row: 7 - pos.col % 4, col: 11, facing: Direction::Left }),
+
+                // 4 -> 6
+                (7, 4..=7, Direction::Down) => Some(Position { row: 15 - pos.col, col: 8, facing: Direction::Right }),
+                // 4 -> 1
+                (4, 4..=7, Direction::Up) => Some(Position { row: pos.col % 4, col: 8, facing: Direction::Right }),
+
+                // 5 -> 6
+                (7, 0..=3, Direction::Down) => Some(Position { row: 11, col: 11 - pos.col, facing: Direction::Right }),
+                // 5 -> 3
+                (4..=7, 0, Direction::Left) => Some(Position { row: 11, col: 15 - pos.col, facing: Direction::Left }),
+                // 5 -> 1
+                (4, 0..=3, Direction::Up) => Some(Position { row: 0, col: 11 - pos.col, facing: Direction::Right }),
+
+                // 6 -> 5
+                (11, 8..=11, Direction::Down) => Some(Position { row: 7, col: 11 - pos.col, facing: Direction::Up }),
+                // 6 -> 4
+                (8..=11, 8, Direction::Left) => Some(Position { row: 7, col: 15 - pos.row, facing: Direction::Up }),
+
+                _ => None
+            }
+        }), 5031);
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day22>(Day22::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::visualize::{self, Color, Frame, Pixel, Render};
+use aoc_common::{end_of_input, lines, Direction, Generator, Grid, ParseError, Point2, Rng, Solution};
+
+fn parse(rows: &Vec<String>) -> Result<Vec<Point2>, ParseError> {
+    let mut elves = vec!();
+
+    for (row_idx, row) in lines(rows).enumerate() {
+        for (col_idx, col) in row.chars().enumerate() {
+            match col.text {
+                "#" => elves.push(Point2::new(col_idx as i64, row_idx as i64)),
+                "." => (),
+                _ => return Err(col.error("expected # or .")),
+            }
+        }
+    }
+
+    if elves.is_empty() {
+        return Err(end_of_input(rows).error("expected at least one elf"));
+    }
+
+    Ok(elves)
+}
+
+// the smallest rectangle containing all elves
+fn occupied(positions: &Vec<Point2>) -> Grid<bool> {
+    let min_x = positions.iter().map(|p| p.x).min().unwrap();
+    let max_x = positions.iter().map(|p| p.x).max().unwrap();
+    let min_y = positions.iter().map(|p| p.y).min().unwrap();
+    let max_y = positions.iter().map(|p| p.y).max().unwrap();
+
+    let mut grid = Grid::new((max_x - min_x) as usize + 1, (max_y - min_y) as usize + 1, false)
+        .with_origin(Point2::new(min_x, min_y).into());
+
+    for &pos in positions {
+        grid[pos] = true;
+    }
+
+    grid
+}
+
+struct Grove<'a>(&'a Vec<Point2>);
+
+impl Render for Grove<'_> {
+    fn render(&self) -> Frame {
+        occupied(self.0).map(|&elf| if elf { Pixel::new('#', Color::GREEN) } else { Pixel::new('.', Color::GREY) })
+    }
+}
+
+fn is_occupied(grid: &Grid<bool>, pos: Point2) -> bool {
+    grid.get(pos) == Some(&true)
+}
+
+fn has_neighbors(grid: &Grid<bool>, pos: Point2) -> bool {
+    grid.neighbours8(pos).any(|p| grid[p])
+}
+
+fn find_proposals(elves: &Vec<Point2>, directions: &Vec<Direction>) -> Vec<Option<Point2>> {
+    let grid = occupied(elves);
+    let mut proposals: Vec<Option<Point2>> = Vec::with_capacity(elves.len());
+
+    for &pos in elves {
+        let mut dest = None;
+
+        if has_neighbors(&grid, pos) {
+            for &dir in directions {
+                let new_pos = pos + dir.vector();
+                let side = dir.vector().rotate_right();
+
+                if ![new_pos - side, new_pos, new_pos + side].iter().any(|&p| is_occupied(&grid, p)) {
+                    dest = Some(new_pos);
+                    break;
+                }
+            }
+        }
+
+        proposals.push(dest);
+    }
+
+    proposals
+}
+
+fn do_round(elves: &mut Vec<Point2>, directions: &Vec<Direction>) -> bool {
+    let proposals = find_proposals(elves, directions);
+    let mut counts: HashMap<Point2, i32> = HashMap::new();
+
+    proposals.iter().for_each(|p| if let Some(pos) = p {
+        counts.insert(*pos, *counts.get(pos).unwrap_or(&0) + 1);
+    });
+
+    let mut changed = false;
+    proposals.iter().enumerate().for_each(|(idx, p)| if let Some(pos) = p {
+        if *counts.get(pos).unwrap() == 1 {
+            elves[idx] = *pos;
+            changed = true;
+        }
+    });
+    visualize::show(&Grove(elves));
+
+    changed
+}
+
+fn part1(elves: &Vec<Point2>) -> usize {
+    let mut elves = elves.clone();
+    let mut directions = VecDeque::from([Direction::Up, Direction::Down, Direction::Left, Direction::Right]);
+
+    for _ in 0..10 {
+        do_round(&mut elves, &directions.iter().cloned().collect());
+        let tmp = directions.pop_front().unwrap();
+        directions.push_back(tmp);
+    }
+
+    occupied(&elves).iter().filter(|(_, &elf)| !elf).count()
+}
+
+fn part2(elves: &Vec<Point2>) -> usize {
+    let mut elves = elves.clone();
+    let mut directions = VecDeque::from([Direction::Up, Direction::Down, Direction::Left, Direction::Right]);
+
+    let mut rounds = 1;
+    while do_round(&mut elves, &directions.iter().cloned().collect()) {
+        rounds += 1;
+        let tmp = directions.pop_front().unwrap();
+        directions.push_back(tmp);
+    }
+
+    rounds
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Point2>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<Point2>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<Point2>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Point2>) -> usize {
+        part2(input)
+    }
+}
+
+impl Generator for Day23 {
+    // counted in the side of the square the elves start in
+    const SIZE: usize = 73;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            input.extend((0..size.max(1)).map(|_| if rng.chance(0.5) { '#' } else { '.' }));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day23>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day23>(20, &[1, 2]);
+    }
+}
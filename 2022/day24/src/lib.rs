@@ -0,0 +1,210 @@
+use aoc_common::search::bfs;
+use aoc_common::visualize::{self, Color, Frame, Pixel, Render};
+use aoc_common::{end_of_input, lines, Generator, Grid, NEIGHBOURS4, ParseError, Rng, Solution, Token};
+
+#[derive(Clone, PartialEq)]
+pub struct Valley {
+    blizzards: Grid<u8>,
+    // the openings in the walls, just outside of the valley
+    entrance: (isize, isize),
+    exit: (isize, isize),
+}
+
+// the walls aren't part of the valley, this is the column of the opening within it
+fn find_opening(row: Token) -> Result<isize, ParseError> {
+    let openings = row.chars().filter(|c| c.text == ".").collect::<Vec<_>>();
+    match openings.as_slice() {
+        [opening] if opening.column > 1 && opening.column < row.text.len() => Ok(opening.column as isize - 2),
+        _ => Err(row.error("expected a wall with a single opening")),
+    }
+}
+
+fn parse(rows: &Vec<String>) -> Result<Valley, ParseError> {
+    let lines = lines(rows).collect::<Vec<_>>();
+    let [first, valley @ .., last] = lines.as_slice() else {
+        return Err(end_of_input(rows).error("expected a valley surrounded by walls"));
+    };
+    let mut inner_rows = vec!();
+    for row in valley {
+        let inner = row.strip_prefix("#")?.strip_suffix("#")?;
+        if inner.text.len() + 2 != first.text.len() {
+            return Err(row.error(format!("expected a row of width {}", first.text.len())));
+        }
+        inner_rows.push(inner);
+    }
+    let blizzards = Grid::parse(inner_rows, |c| match c.text {
+        "." | ">" | "<" | "v" | "^" => Ok(c.text.as_bytes()[0]),
+        _ => Err(c.error("expected ., >, <, v or ^")),
+    })?;
+    if blizzards.height() == 0 {
+        return Err(last.error("expected a valley between the walls"));
+    }
+
+    Ok(Valley {
+        entrance: (-1, find_opening(*first)?),
+        exit: (blizzards.height() as isize, find_opening(*last)?),
+        blizzards,
+    })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// the blizzards move straight and wrap around, so whether one is at the position can be told from the start
+fn is_free(valley: &Valley, pos: (isize, isize), minute: usize) -> bool {
+    if pos == valley.entrance || pos == valley.exit {
+        return true;
+    }
+    if !valley.blizzards.contains(pos) {
+        return false;
+    }
+    let (row, col, minute) = (pos.0, pos.1, minute as isize);
+    *valley.blizzards.get_wrapping((row, col - minute)) != b'>'
+        && *valley.blizzards.get_wrapping((row, col + minute)) != b'<'
+        && *valley.blizzards.get_wrapping((row - minute, col)) != b'v'
+        && *valley.blizzards.get_wrapping((row + minute, col)) != b'^'
+}
+
+struct Expedition<'a> {
+    valley: &'a Valley,
+    pos: (isize, isize),
+    minute: usize,
+}
+
+impl Render for Expedition<'_> {
+    // the valley within its walls, the number of blizzards where more than one meet
+    fn render(&self) -> Frame {
+        let blizzards = &self.valley.blizzards;
+        let mut frame = Grid::new(blizzards.width() + 2, blizzards.height() + 2, Pixel::new('#', Color::GREY))
+            .with_origin((-1, -1));
+        for pos in blizzards.positions() {
+            let minute = self.minute as isize;
+            let here = [(b'>', (0, -minute)), (b'<', (0, minute)), (b'v', (-minute, 0)), (b'^', (minute, 0))].iter()
+                .filter(|(blizzard, (rows, cols))| blizzards.get_wrapping((pos.0 + rows, pos.1 + cols)) == blizzard)
+                .map(|&(blizzard, _)| blizzard as char)
+                .collect::<Vec<_>>();
+            frame[pos] = match here.as_slice() {
+                [] => Pixel::new('.', Color::GREY),
+                [blizzard] => Pixel::new(*blizzard, Color::CYAN),
+                _ => Pixel::new(char::from_digit(here.len() as u32, 10).unwrap(), Color::BLUE),
+            };
+        }
+        frame[self.valley.entrance] = Pixel::new('.', Color::GREY);
+        frame[self.valley.exit] = Pixel::new('.', Color::GREY);
+        frame[self.pos] = Pixel::new('E', Color::RED);
+        frame
+    }
+}
+
+// the minute of the arrival if there is a way, the states are the position and the minute within the cycle of
+// the blizzards
+fn crossing(valley: &Valley, from: (isize, isize), to: (isize, isize), minute: usize) -> Option<usize> {
+    let (width, height) = (valley.blizzards.width(), valley.blizzards.height());
+    let period = width / gcd(width, height) * height;
+
+    let paths = bfs([(from, minute % period)], |&(pos, phase)| {
+        let next = (phase + 1) % period;
+        [(0, 0)].iter().chain(NEIGHBOURS4.iter())
+            .map(|step| (pos.0 + step.0, pos.1 + step.1))
+            .filter(|&p| is_free(valley, p, next))
+            .map(|p| (p, next))
+            .collect::<Vec<_>>()
+    }, |&(pos, _)| pos == to);
+
+    if visualize::is_active() {
+        let path = paths.goal().and_then(|goal| paths.path(goal)).unwrap_or_default();
+        for (step, (pos, _)) in path.into_iter().enumerate() {
+            visualize::show(&Expedition { valley, pos, minute: minute + step });
+        }
+    }
+
+    paths.goal_distance().map(|distance| minute + distance as usize)
+}
+
+fn cross(valley: &Valley, from: (isize, isize), to: (isize, isize), minute: usize) -> usize {
+    crossing(valley, from, to, minute).expect("There is a way through the valley")
+}
+
+fn part1(valley: &Valley) -> i32 {
+    cross(valley, valley.entrance, valley.exit, 0) as i32
+}
+
+fn part2(valley: &Valley) -> usize {
+    let there = cross(valley, valley.entrance, valley.exit, 0);
+    let back = cross(valley, valley.exit, valley.entrance, there);
+    cross(valley, valley.entrance, valley.exit, back)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Valley;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(rows: &Vec<String>) -> Result<Valley, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Valley) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Valley) -> usize {
+        part2(input)
+    }
+}
+
+// whether there's a way there, back and there again
+fn has_round_trip(valley: &Valley) -> bool {
+    crossing(valley, valley.entrance, valley.exit, 0)
+        .and_then(|there| crossing(valley, valley.exit, valley.entrance, there))
+        .and_then(|back| crossing(valley, valley.entrance, valley.exit, back))
+        .is_some()
+}
+
+impl Generator for Day24 {
+    // counted in the width of the valley, which is five times its height
+    const SIZE: usize = 120;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        let (width, height) = (size.max(2), (size / 5).max(1));
+        loop {
+            let mut input = format!("#.{}\n", "#".repeat(width));
+            for _ in 0..height {
+                input.push('#');
+                for col in 0..width {
+                    // a blizzard going up or down the columns of the openings would leave the valley
+                    let blizzards = if col == 0 || col == width - 1 { &['>', '<'][..] } else { &['>', '<', 'v', '^'] };
+                    input.push(if rng.chance(0.3) { *rng.choose(blizzards) } else { '.' });
+                }
+                input += "#\n";
+            }
+            input += &format!("{}.#\n", "#".repeat(width));
+
+            let rows = input.lines().map(String::from).collect();
+            if has_round_trip(&parse(&rows).expect("The made up valley parses")) {
+                return input;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<Day24>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day24>(40, &[1, 2]);
+    }
+}
@@ -0,0 +1,239 @@
+use aoc_common::generate::streamed_input;
+use aoc_common::{lines, Generator, ParseError, Rng, Solution, Stream, StreamError, Streaming, Token};
+
+// the digits from -2 to 2
+const DIGITS: &[u8; 5] = b"=-012";
+
+// the digits are all SNAFU ones, none if the number doesn't fit into an i128, which holds every number
+// of up to 55 digits and some of 56
+fn from_snafu(snafu: &str) -> Option<i128> {
+    let digit_value = |digit| match digit {
+        b'=' => -2,
+        b'-' => -1,
+        _ => (digit - b'0') as i64,
+    };
+    // every number of up to 27 digits fits into an i64, which is quicker to work out
+    if snafu.len() <= 27 {
+        return Some(snafu.bytes().fold(0i64, |num, digit| num * 5 + digit_value(digit)).into());
+    }
+
+    snafu.bytes().try_fold(0i128, |num, digit| {
+        let digit = digit_value(digit) as i128;
+        // five times the digits so far may be just beyond the ends of i128 when the number isn't,
+        // moving one of the five over to the last digit keeps it within
+        num.checked_mul(5).and_then(|num| num.checked_add(digit))
+            .or_else(|| (num + digit.signum()).checked_mul(5)?.checked_add(digit - 5 * digit.signum()))
+    })
+}
+
+// any number has SNAFU digits, the negative ones start with - or =
+fn to_snafu(number: i128) -> String {
+    let mut digits = vec!();
+    let mut rest = number;
+    loop {
+        // the digit which leaves a multiple of 5, dividing that in two steps can't overflow
+        let digit = (rest % 5 + 7) % 5 - 2;
+        digits.push(DIGITS[(digit + 2) as usize]);
+        rest = rest / 5 + (rest % 5 - digit) / 5;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().map(|&digit| digit as char).collect()
+}
+
+// the way to_snafu() writes numbers: SNAFU digits without leading zeros
+fn is_canonical(snafu: &str) -> bool {
+    snafu.bytes().all(|digit| DIGITS.contains(&digit)) && (snafu == "0" || !snafu.is_empty() && !snafu.starts_with('0'))
+}
+
+fn parse_number(row: Token<'_>) -> Result<i128, ParseError> {
+    match row.chars().find(|c| !"=-012".contains(c.text)) {
+        Some(digit) => Err(digit.error("expected a SNAFU digit (=, -, 0, 1 or 2)")),
+        None if row.text.is_empty() => Err(row.error("expected a SNAFU number")),
+        None if !is_canonical(row.text) => Err(row.error("expected a SNAFU number without leading zeros")),
+        None => from_snafu(row.text).ok_or_else(|| row.error("expected a SNAFU number which fits into 128 bits")),
+    }
+}
+
+fn parse(rows: &Vec<String>) -> Result<Vec<i128>, ParseError> {
+    lines(rows).map(parse_number).collect()
+}
+
+// it takes more numbers than any input holds for the sum to overflow
+fn add(sum: i128, number: i128) -> i128 {
+    sum.checked_add(number).expect("The sum fits into an i128")
+}
+
+fn part1(numbers: &Vec<i128>) -> String {
+    to_snafu(numbers.iter().fold(0, |sum, &number| add(sum, number)))
+}
+
+// the last day has a single puzzle, its second star comes with all the others
+const NO_PART2: &str = "there is no part 2";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i128>;
+    type Part1 = String;
+    type Part2 = &'static str;
+
+    // there's nothing to run or submit
+    const UNSOLVED_PARTS: &'static [u8] = &[2];
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<i128>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<i128>) -> String {
+        part1(input)
+    }
+
+    fn part2(_: &Vec<i128>) -> &'static str {
+        NO_PART2
+    }
+}
+
+impl Streaming for Day25 {
+    fn stream_part1(input: &mut Stream) -> Result<String, StreamError> {
+        let sum = input.try_fold_rows(0, |sum, row| Ok(add(sum, parse_number(row)?)))?;
+        Ok(to_snafu(sum))
+    }
+
+    fn stream_part2(_: &mut Stream) -> Result<&'static str, StreamError> {
+        Err(StreamError::NoPart(2))
+    }
+
+    fn generate(rng: &mut Rng, input: &mut String) {
+        // like the numbers of the real inputs, which are all positive
+        input.push_str(&to_snafu(rng.range(1..=5i64.pow(8)).into()));
+        input.push('\n');
+    }
+}
+
+impl Generator for Day25 {
+    // counted in numbers
+    const SIZE: usize = 120;
+
+    fn generate_input(rng: &mut Rng, size: usize) -> String {
+        streamed_input::<Day25>(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use aoc_common::check_examples;
+    use aoc_common::examples::check_streamed_examples;
+    use aoc_common::generate::check_generator;
+
+    use super::*;
+
+    #[test]
+    fn from_snafu_works() {
+        assert_eq!(from_snafu("0"), Some(0));
+        assert_eq!(from_snafu("1"), Some(1));
+        assert_eq!(from_snafu("2"), Some(2));
+        assert_eq!(from_snafu("1="), Some(3));
+        assert_eq!(from_snafu("1-"), Some(4));
+        assert_eq!(from_snafu("10"), Some(5));
+        assert_eq!(from_snafu("11"), Some(6));
+        assert_eq!(from_snafu("12"), Some(7));
+        assert_eq!(from_snafu("2="), Some(8));
+        assert_eq!(from_snafu("2-"), Some(9));
+        assert_eq!(from_snafu("20"), Some(10));
+        assert_eq!(from_snafu("1=0"), Some(15));
+        assert_eq!(from_snafu("1-0"), Some(20));
+        assert_eq!(from_snafu("1=11-2"), Some(2022));
+        assert_eq!(from_snafu("1-0---0"), Some(12345));
+        assert_eq!(from_snafu("1121-1110-1=0"), Some(314159265));
+    }
+
+    #[test]
+    fn to_snafu_works() {
+        assert_eq!(to_snafu(0), "0");
+        assert_eq!(to_snafu(1), "1");
+        assert_eq!(to_snafu(2), "2");
+        assert_eq!(to_snafu(3), "1=");
+        assert_eq!(to_snafu(4), "1-");
+        assert_eq!(to_snafu(5), "10");
+        assert_eq!(to_snafu(6), "11");
+        assert_eq!(to_snafu(7), "12");
+        assert_eq!(to_snafu(8), "2=");
+        assert_eq!(to_snafu(9), "2-");
+        assert_eq!(to_snafu(10), "20");
+        assert_eq!(to_snafu(15), "1=0");
+        assert_eq!(to_snafu(20), "1-0");
+        assert_eq!(to_snafu(2022), "1=11-2");
+        assert_eq!(to_snafu(12345), "1-0---0");
+        assert_eq!(to_snafu(314159265), "1121-1110-1=0");
+    }
+
+    // numbers of all sizes, the ends of i64 and i128 included
+    fn made_up_numbers(rng: &mut Rng) -> Vec<i128> {
+        let mut numbers = vec!(0, 1, -1, 2, -2, 3, -3, i64::MAX.into(), i64::MIN.into(), i128::MAX, i128::MIN);
+        for _ in 0..5000 {
+            let number = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) as i128;
+            numbers.push(number >> rng.below(128));
+            numbers.push((rng.next_u64() as i64 >> rng.below(64)).into());
+        }
+        numbers
+    }
+
+    #[test]
+    fn numbers_round_trip() {
+        for number in made_up_numbers(&mut Rng::new(25)) {
+            let snafu = to_snafu(number);
+            assert!(is_canonical(&snafu), "{number} became {snafu}");
+            assert_eq!(from_snafu(&snafu), Some(number), "{number} became {snafu}");
+        }
+    }
+
+    #[test]
+    fn canonical_snafu_round_trips() {
+        let mut rng = Rng::new(5);
+        for _ in 0..10000 {
+            let len = 1 + rng.below(55);
+            let snafu = (0..len)
+                .map(|idx| *rng.choose(if idx == 0 && len > 1 { &b"=-12"[..] } else { &DIGITS[..] }) as char)
+                .collect::<String>();
+            assert!(is_canonical(&snafu));
+            assert_eq!(to_snafu(from_snafu(&snafu).unwrap()), snafu);
+        }
+
+        assert!(is_canonical("0"));
+        assert!(!is_canonical(""));
+        assert!(!is_canonical("00"));
+        assert!(!is_canonical("01="));
+        assert!(!is_canonical("1+"));
+        assert!(Day25::parse(&vec!("0-".to_string())).is_err());
+    }
+
+    #[test]
+    fn too_large_numbers_overflow() {
+        assert!(from_snafu(&"2".repeat(55)).is_some());
+        assert!(from_snafu(&"=".repeat(55)).is_some());
+        assert!(from_snafu(&format!("1{}", "=".repeat(55))).is_some());
+        assert_eq!(from_snafu(&format!("1{}", "0".repeat(55))), None);
+        assert_eq!(from_snafu(&format!("-{}", "0".repeat(55))), None);
+        assert!(Day25::parse(&vec!(format!("1{}", "0".repeat(55)))).is_err());
+        assert!(panic::catch_unwind(|| part1(&vec!(i128::MAX, 1))).is_err());
+    }
+
+    #[test]
+    fn examples() {
+        check_examples::<Day25>("examples");
+    }
+
+    #[test]
+    fn streamed_examples() {
+        check_streamed_examples::<Day25>("examples");
+    }
+
+    #[test]
+    fn generated_inputs() {
+        check_generator::<Day25>(Day25::SIZE, &[1, 2]);
+    }
+}
@@ -0,0 +1,93 @@
+//! What the parts of a puzzle come up with, converted from whatever type a solution computes it as.
+
+use std::fmt::{Display, Formatter};
+
+use crate::grid::Grid;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Letters drawn with lit pixels, e.g. on a screen, which still have to be read.
+    Glyphs(Grid<bool>),
+}
+
+impl Answer {
+    /// The type of the answer as shown to other tools.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "string",
+            Answer::Glyphs(_) => "glyphs",
+        }
+    }
+
+    /// What to send to the website, glyphs can't be sent until they are read.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Glyphs(_) => None,
+            answer => Some(answer.to_string()),
+        }
+    }
+}
+
+/// Glyphs are drawn with `#` and blanks, a line for each row of pixels.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Glyphs(pixels) => write!(f, "{}", pixels.render(|&lit| if lit { '#' } else { ' ' })),
+        }
+    }
+}
+
+macro_rules! impl_from_integers {
+    ($($int:ty),+) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(n: $int) -> Answer {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(pixels: Grid<bool>) -> Answer {
+        Answer::Glyphs(pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(Answer::from(-12i32), Answer::Integer(-12));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("2=-1=0").kind(), "string");
+        assert_eq!(Answer::from(7usize).submission(), Some("7".to_string()));
+
+        let glyphs = Answer::from(Grid::from_rows(vec!(vec!(true, false, true), vec!(true, true, false))));
+        assert_eq!(glyphs.to_string(), "# #\n## ");
+        assert_eq!(glyphs.kind(), "glyphs");
+        assert_eq!(glyphs.submission(), None);
+    }
+}
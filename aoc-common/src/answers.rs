@@ -0,0 +1,155 @@
+//! The known answers of a puzzle input, kept in an `answers.toml` next to the input.
+//!
+//! Only the small part of TOML needed for it is understood:
+//!
+//! ```toml
+//! # comments and blank lines are ignored
+//! part1 = 26558
+//! part2 = "FZBPBFZF"
+//! # multi-line answers like rendered letters
+//! part2 = '''
+//! #### ...
+//! '''
+//! ```
+
+use crate::parse::{end_of_input, lines, ParseError, Token};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The result of comparing an answer with the known one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+fn parse_string(value: Token) -> Result<String, ParseError> {
+    let mut string = String::new();
+    let mut chars = value.strip_prefix("\"")?.chars();
+    while let Some(c) = chars.next() {
+        match c.text {
+            "\"" => return match chars.next() {
+                None => Ok(string),
+                Some(rest) => Err(rest.error("expected the end of the line")),
+            },
+            "\\" => match chars.next().map(|escaped| (escaped, escaped.text)) {
+                Some((_, "\"")) => string.push('"'),
+                Some((_, "\\")) => string.push('\\'),
+                Some((_, "n")) => string.push('\n'),
+                Some((escaped, _)) => return Err(escaped.error("expected one of the escapes \\\", \\\\ or \\n")),
+                None => break,
+            },
+            _ => string += c.text,
+        }
+    }
+    Err(value.end().error("expected \"\\\"\""))
+}
+
+impl Answers {
+    pub fn parse(rows: &[String]) -> Result<Answers, ParseError> {
+        let mut answers = Answers::default();
+        let mut lines = lines(rows);
+
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if line.text.is_empty() || line.text.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once("=")?;
+            let (key, value) = (key.trim(), value.trim());
+            let answer = match value.text {
+                "'''" => {
+                    let mut multi_line: Vec<&str> = vec!();
+                    loop {
+                        match lines.next() {
+                            Some(line) if line.text == "'''" => break,
+                            Some(line) => multi_line.push(line.text),
+                            None => return Err(end_of_input(rows).error("expected the closing '''")),
+                        }
+                    }
+                    multi_line.join("\n")
+                }
+                text if text.starts_with('"') => parse_string(value)?,
+                _ => value.parse::<i128>()?.to_string(),
+            };
+
+            let slot = match key.text {
+                "part1" => &mut answers.part1,
+                "part2" => &mut answers.part2,
+                _ => return Err(key.error("expected part1 or part2")),
+            };
+            if slot.replace(answer).is_some() {
+                return Err(key.error("the answer is given twice"));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Compares the answer of a part with the known one, trailing line breaks don't matter.
+    pub fn check(&self, part: u8, answer: &str) -> Check {
+        match self.get(part) {
+            None => Check::Unknown,
+            Some(expected) if expected.trim_end_matches('\n') == answer.trim_end_matches('\n') => Check::Pass,
+            Some(expected) => Check::Fail { expected: expected.to_string() },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse(&rows(concat!(
+            "# day 10\n",
+            "part1 = 13140\n",
+            "\n",
+            "part2 = '''\n",
+            "##  ##\n",
+            "###   \n",
+            "'''\n"))).unwrap();
+        assert_eq!(answers.get(1), Some("13140"));
+        assert_eq!(answers.get(2), Some("##  ##\n###   "));
+
+        let answers = Answers::parse(&rows("part2 = \"say \\\"hi\\\"\"")).unwrap();
+        assert_eq!(answers, Answers { part1: None, part2: Some("say \"hi\"".to_string()) });
+    }
+
+    #[test]
+    fn rejects_malformed_answers() {
+        let err = Answers::parse(&rows("part1 = 1\npart3 = 2")).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected part1 or part2, found \"part3\"");
+        assert!(Answers::parse(&rows("part1 = 1\npart1 = 2")).is_err());
+        assert!(Answers::parse(&rows("part1 = 12a")).is_err());
+        assert!(Answers::parse(&rows("part1 = \"open")).is_err());
+        assert!(Answers::parse(&rows("part1 = '''\n#")).is_err());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers { part1: Some("24000".to_string()), part2: None };
+        assert_eq!(answers.check(1, "24000"), Check::Pass);
+        assert_eq!(answers.check(1, "23000"), Check::Fail { expected: "24000".to_string() });
+        assert_eq!(answers.check(2, "45000"), Check::Unknown);
+    }
+}
@@ -0,0 +1,195 @@
+//! Finding where a simulation starts to repeat itself, to skip ahead to a step far beyond what can be
+//! simulated.
+//!
+//! Steps are counted from the initial state, which is step 0. The values of a simulation, like the
+//! height of a tower, are kept with the steps they were reached at so that [Cycle::extrapolate] can
+//! continue them.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// From step `start` on the same `length` steps repeat over and over.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before the end of the first cycle which is in the same state as step `n`.
+    pub fn equivalent_step(&self, n: u64) -> usize {
+        if n < (self.start + self.length) as u64 {
+            return n as usize;
+        }
+        self.start + ((n - self.start as u64) % self.length as u64) as usize
+    }
+
+    /// How much the value grows with each cycle, `values[i]` being the value at step `i`.
+    pub fn delta(&self, values: &[i64]) -> i64 {
+        values[self.start + self.length] - values[self.start]
+    }
+
+    /// The value at step `n`, the values need to reach the end of the first cycle.
+    pub fn extrapolate(&self, values: &[i64], n: u64) -> i64 {
+        let step = self.equivalent_step(n);
+        let cycles = (n - step as u64) / self.length as u64;
+        values[step] + cycles as i64 * self.delta(values)
+    }
+}
+
+/// Floyd's tortoise and hare, only ever keeps two states. Never returns if the states don't repeat.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // the hare is a multiple of the length ahead, so both meet at the start when walking together
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, like [floyd] but with fewer steps. Never returns if the states don't repeat.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Remembers a key for each step of a simulation, for states which are too large to compare as a
+/// whole (or can't be stepped on their own) but are described well enough by a part of them.
+#[derive(Clone, Debug, Default)]
+pub struct History<K> {
+    steps: HashMap<K, usize>,
+    values: Vec<i64>,
+}
+
+impl<K: Eq + Hash> History<K> {
+    pub fn new() -> History<K> {
+        History { steps: HashMap::new(), values: vec!() }
+    }
+
+    /// Records the next step, starting with the initial state, and returns the cycle once its key was
+    /// seen before.
+    pub fn push(&mut self, key: K, value: i64) -> Option<Cycle> {
+        let step = self.values.len();
+        self.values.push(value);
+        match self.steps.get(&key) {
+            Some(&start) => Some(Cycle { start, length: step - start }),
+            None => {
+                self.steps.insert(key, step);
+                None
+            }
+        }
+    }
+
+    /// The values of all steps so far.
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+/// The longest cycle of at least `min_length` at the end of the differences between the values of
+/// consecutive steps, when it was repeated at least twice. `diffs[i]` leads from step `i` to `i + 1`.
+///
+/// Unlike a [History] this only looks at the values, so a long enough `min_length` has to rule out
+/// repetitions by chance.
+pub fn repeating_suffix(diffs: &[i64], min_length: usize) -> Option<Cycle> {
+    (min_length.max(1)..=diffs.len() / 2).rev()
+        .find(|&length| diffs[diffs.len() - length..] == diffs[diffs.len() - 2 * length..diffs.len() - length])
+        .map(|length| Cycle { start: diffs.len() - 2 * length, length })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn next(&n: &i64) -> i64 {
+        if n < 5 { n + 1 } else { 3 }
+    }
+
+    #[test]
+    fn finds_cycles() {
+        let cycle = Cycle { start: 3, length: 3 };
+        assert_eq!(floyd(0, next), cycle);
+        assert_eq!(brent(0, next), cycle);
+
+        let mut history = History::new();
+        let found = (0..).scan(0, |n, _| Some(std::mem::replace(n, next(n))))
+            .find_map(|n| history.push(n, n));
+        assert_eq!(found, Some(cycle));
+        assert_eq!(history.values(), [0, 1, 2, 3, 4, 5, 3]);
+        assert_eq!(cycle.equivalent_step(4), 4);
+        assert_eq!(cycle.equivalent_step(1000), 4);
+        assert_eq!(cycle.extrapolate(history.values(), 1000), 4);
+
+        // a pseudo random sequence of numbers below 1000
+        let random = |&n: &u64| (n * n + 7) % 1009 % 1000;
+        let mut history = History::new();
+        let mut n = 1;
+        let found = loop {
+            if let Some(cycle) = history.push(n, 0) {
+                break cycle;
+            }
+            n = random(&n);
+        };
+        assert_eq!(floyd(1, random), found);
+        assert_eq!(brent(1, random), found);
+    }
+
+    #[test]
+    fn extrapolates_values() {
+        let diffs = [4, 1, 2, 2, 1, 3, 1, 2, 2, 1, 3, 1];
+        let cycle = repeating_suffix(&diffs, 2).unwrap();
+        assert_eq!(cycle, Cycle { start: 2, length: 5 });
+        assert_eq!(repeating_suffix(&diffs, 6), None);
+
+        let values = diffs.iter().scan(0, |sum, diff| { *sum += diff; Some(*sum) }).collect::<Vec<_>>();
+        let values = [&[0][..], &values].concat();
+        assert_eq!(cycle.delta(&values), 9);
+        assert_eq!(cycle.extrapolate(&values, 12), values[12]);
+        assert_eq!(cycle.extrapolate(&values, 17), values[12] + 9);
+        assert_eq!(cycle.extrapolate(&values, 1_000_000_000_002), 5 + 200_000_000_000 * 9);
+    }
+}
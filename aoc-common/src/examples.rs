@@ -0,0 +1,272 @@
+//! The examples of a day: every `examples/NAME.in` comes with an `examples/NAME.expected`
+//! holding the answers in the format of an [answers file](crate::answers), parts without an
+//! answer aren't run and neither are the [unsolved](Solution::UNSOLVED_PARTS) ones. A single test per day checks all of them, so adding an example doesn't
+//! need any code.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::answers::{Answers, Check};
+use crate::input::{readlines, Source};
+use crate::solution::Solution;
+use crate::stream::{Generated, Stream, Streaming};
+
+/// The `*.in` files within the directory, sorted by name.
+fn example_inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut inputs = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .filter(|path| path.as_ref().map_or(true, |p| p.extension().is_some_and(|ext| ext == "in")))
+        .collect::<io::Result<Vec<_>>>()?;
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// The answers of the example from its `.expected` file, which may leave all of them out while no
+/// part is solved yet.
+fn expected_answers(input: &Path, unsolved: &[u8]) -> Result<Answers, String> {
+    let expected = input.with_extension("expected");
+    let rows = readlines(&expected).map_err(|err| format!("unable to read \"{}\": {err}", expected.display()))?;
+    let answers = Answers::parse(&rows)
+        .map_err(|err| format!("unable to parse \"{}\": {}", expected.display(), err.diagnostic(&rows)))?;
+    if answers.part1.is_none() && answers.part2.is_none() && !(unsolved.contains(&1) && unsolved.contains(&2)) {
+        return Err(format!("\"{}\" doesn't contain any answers", expected.display()));
+    }
+    Ok(answers)
+}
+
+fn check_example<S: Solution>(input: &Path) -> Result<(), String> {
+    let answers = expected_answers(input, S::UNSOLVED_PARTS)?;
+
+    let rows = readlines(input).map_err(|err| format!("unable to read \"{}\": {err}", input.display()))?;
+    let parsed = S::parse(&rows)
+        .map_err(|err| format!("unable to parse \"{}\": {}", input.display(), err.diagnostic(&rows)))?;
+
+    let mut failures = vec!();
+    for part in [1, 2] {
+        if answers.get(part).is_none() || S::UNSOLVED_PARTS.contains(&part) {
+            continue;
+        }
+        let answer: Answer = if part == 1 { S::example_part1(&parsed).into() } else { S::example_part2(&parsed).into() };
+        let answer = answer.to_string();
+        if let Check::Fail { expected } = answers.check(part, &answer) {
+            failures.push(format!("\"{}\" part {part}: expected {expected:?}, got {answer:?}", input.display()));
+        }
+    }
+    if failures.is_empty() { Ok(()) } else { Err(failures.join("\n")) }
+}
+
+/// Checks all examples within the directory, returns how many there are or what went wrong.
+pub fn run_examples<S: Solution>(dir: impl AsRef<Path>) -> Result<usize, Vec<String>> {
+    let dir = dir.as_ref();
+    let inputs = example_inputs(dir).map_err(|err| vec!(format!("unable to list \"{}\": {err}", dir.display())))?;
+    if inputs.is_empty() {
+        return Err(vec!(format!("there are no examples in \"{}\"", dir.display())));
+    }
+
+    let failures = inputs.iter()
+        .filter_map(|input| check_example::<S>(input).err())
+        .collect::<Vec<_>>();
+    if failures.is_empty() { Ok(inputs.len()) } else { Err(failures) }
+}
+
+/// Panics with all failed examples within the directory, meant to be called from a test.
+pub fn check_examples<S: Solution>(dir: impl AsRef<Path>) {
+    if let Err(failures) = run_examples::<S>(dir) {
+        panic!("{} failed:\n{}", if failures.len() == 1 { "An example" } else { "Examples" }, failures.join("\n"));
+    }
+}
+
+fn stream_part<S: Streaming>(part: u8, stream: &mut Stream) -> Result<String, String> {
+    let answer: Answer = match part {
+        1 => S::stream_part1(stream).map_err(|err| err.to_string())?.into(),
+        _ => S::stream_part2(stream).map_err(|err| err.to_string())?.into(),
+    };
+    Ok(answer.to_string())
+}
+
+/// The parts with answers in the examples are checked, the others may not be solved yet.
+fn check_streamed_example<S: Streaming>(input: &Path, parts: &mut Vec<u8>) -> Result<(), String> {
+    let answers = expected_answers(input, S::UNSOLVED_PARTS)?;
+    let mut failures = vec!();
+    for part in [1, 2] {
+        if answers.get(part).is_none() || S::UNSOLVED_PARTS.contains(&part) {
+            continue;
+        }
+        parts.push(part);
+        let mut stream = Stream::open(&Source::File(input.to_path_buf()))
+            .map_err(|err| format!("unable to read \"{}\": {err}", input.display()))?;
+        let answer = stream_part::<S>(part, &mut stream).map_err(|err| format!("\"{}\" part {part}: {err}", input.display()))?;
+        if let Check::Fail { expected } = answers.check(part, &answer) {
+            failures.push(format!("\"{}\" part {part} streamed: expected {expected:?}, got {answer:?}", input.display()));
+        }
+    }
+    if failures.is_empty() { Ok(()) } else { Err(failures.join("\n")) }
+}
+
+/// The streamed parts have to agree with the parsed ones on made up inputs.
+fn check_generated<S: Streaming>(seed: u64, size: u64, parts: &[u8]) -> Result<(), String> {
+    let mut text = String::new();
+    io::Read::read_to_string(&mut Generated::new::<S>(seed, size), &mut text).map_err(|err| err.to_string())?;
+    let rows = text.lines().map(String::from).collect::<Vec<_>>();
+    let parsed = S::parse(&rows).map_err(|err| format!("made up input {seed}: {}", err.diagnostic(&rows)))?;
+
+    let mut failures = vec!();
+    for &part in parts {
+        let expected: Answer = if part == 1 { S::part1(&parsed).into() } else { S::part2(&parsed).into() };
+        let mut stream = Stream::new(Generated::new::<S>(seed, size));
+        match stream_part::<S>(part, &mut stream) {
+            Ok(answer) if answer == expected.to_string() => (),
+            Ok(answer) => failures.push(format!("made up input {seed} part {part} streamed: expected {:?}, got {answer:?}", expected.to_string())),
+            Err(err) => failures.push(format!("made up input {seed} part {part}: {err}")),
+        }
+    }
+    if failures.is_empty() { Ok(()) } else { Err(failures.join("\n")) }
+}
+
+/// Panics unless the streamed parts solve all examples within the directory and agree with the
+/// parsed parts on a few made up inputs, meant to be called from a test.
+pub fn check_streamed_examples<S: Streaming>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let inputs = example_inputs(dir).unwrap_or_else(|err| panic!("unable to list \"{}\": {err}", dir.display()));
+    let mut parts = vec!();
+    let mut failures = inputs.iter()
+        .filter_map(|input| check_streamed_example::<S>(input, &mut parts).err())
+        .collect::<Vec<_>>();
+    parts.sort();
+    parts.dedup();
+    failures.extend((1..=3).filter_map(|seed| check_generated::<S>(seed, 1 << 14, &parts).err()));
+    if !failures.is_empty() {
+        panic!("Streaming failed:\n{}", failures.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::parse::{lines, ParseError};
+    use crate::rng::Rng;
+    use crate::stream::StreamError;
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = usize;
+
+        fn parse(rows: &Vec<String>) -> Result<Vec<i32>, ParseError> {
+            lines(rows).map(|row| row.parse()).collect()
+        }
+
+        fn part1(input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<i32>) -> usize {
+            input.len()
+        }
+    }
+
+    impl Streaming for Sum {
+        fn stream_part1(input: &mut Stream) -> Result<i32, StreamError> {
+            input.try_fold_rows(0, |sum, row| Ok(sum + row.parse::<i32>()?))
+        }
+
+        fn stream_part2(input: &mut Stream) -> Result<usize, StreamError> {
+            // miscounts to be caught
+            input.try_fold_rows(0, |count, row| Ok(count + if row.text == "7" { 2 } else { 1 }))
+        }
+
+        fn generate(rng: &mut Rng, input: &mut String) {
+            input.push_str(&format!("{}\n", rng.range(-9..=6)));
+        }
+    }
+
+    // a day just set up, its parts would panic if they were run
+    struct Unsolved;
+
+    impl Solution for Unsolved {
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = usize;
+
+        const UNSOLVED_PARTS: &'static [u8] = &[1, 2];
+
+        fn parse(rows: &Vec<String>) -> Result<Vec<i32>, ParseError> {
+            Sum::parse(rows)
+        }
+
+        fn part1(_: &Vec<i32>) -> i32 {
+            panic!("part 1 isn't solved yet")
+        }
+
+        fn part2(_: &Vec<i32>) -> usize {
+            panic!("part 2 isn't solved yet")
+        }
+    }
+
+    #[test]
+    fn checks_streamed_parts() {
+        let dir = env::temp_dir().join(format!("aoc-streamed-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.in"), "1\n2\n").unwrap();
+        fs::write(dir.join("a.expected"), "part1 = 3\npart2 = 2\n").unwrap();
+        check_streamed_examples::<Sum>(&dir);
+
+        fs::write(dir.join("b.in"), "7\n").unwrap();
+        fs::write(dir.join("b.expected"), "part2 = 1\n").unwrap();
+        let failure = std::panic::catch_unwind(|| check_streamed_examples::<Sum>(&dir)).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(failure.downcast_ref::<String>().unwrap().contains("b.in\" part 2 streamed: expected \"1\", got \"2\""));
+    }
+
+    #[test]
+    fn checks_all_examples() {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.in"), "1\n2\n").unwrap();
+        fs::write(dir.join("a.expected"), "part1 = 3\npart2 = 2\n").unwrap();
+        fs::write(dir.join("b.in"), "5\n").unwrap();
+        fs::write(dir.join("b.expected"), "part2 = 1\n").unwrap();
+        fs::write(dir.join("notes.txt"), "not an example").unwrap();
+        assert_eq!(run_examples::<Sum>(&dir), Ok(2));
+
+        fs::write(dir.join("c.in"), "5\nx\n").unwrap();
+        fs::write(dir.join("c.expected"), "part1 = 5\n").unwrap();
+        fs::write(dir.join("d.in"), "5\n").unwrap();
+        fs::write(dir.join("d.expected"), "part1 = 6\n").unwrap();
+        fs::write(dir.join("e.in"), "5\n").unwrap();
+        let failures = run_examples::<Sum>(&dir).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(failures.len(), 3);
+        assert!(failures[0].contains("line 2, column 1: expected i32"), "{}", failures[0]);
+        assert!(failures[1].ends_with("part 1: expected \"6\", got \"5\""), "{}", failures[1]);
+        assert!(failures[2].contains("e.expected"), "{}", failures[2]);
+    }
+
+    #[test]
+    fn skips_unsolved_parts() {
+        let dir = env::temp_dir().join(format!("aoc-unsolved-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.in"), "1
+2
+").unwrap();
+        fs::write(dir.join("a.expected"), "# no answers yet
+").unwrap();
+        assert_eq!(run_examples::<Unsolved>(&dir), Ok(1));
+        let failures = run_examples::<Sum>(&dir).unwrap_err();
+        assert!(failures[0].ends_with("doesn't contain any answers"), "{}", failures[0]);
+
+        fs::write(dir.join("a.expected"), "part1 = 3
+part2 = 5
+").unwrap();
+        assert_eq!(run_examples::<Unsolved>(&dir), Ok(1));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
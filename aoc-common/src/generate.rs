@@ -0,0 +1,100 @@
+//! Made up puzzle inputs, to fuzz and benchmark the solutions and to share as the real inputs can't be.
+//!
+//! A [Generator] makes up inputs which are valid the same way the real ones are, e.g. a path leads
+//! through the map or the numbers don't overflow, so that its parts can solve them.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::answer::Answer;
+use crate::rng::Rng;
+use crate::solution::Solution;
+use crate::stream::Streaming;
+
+/// A [Solution] which can make up its own inputs.
+pub trait Generator: Solution {
+    /// The size of a real input, see [Generator::generate_input].
+    const SIZE: usize;
+
+    /// An input of the given size, counted in whatever suits the day: lines, monkeys, the side of
+    /// a map. The same state of the rng gives the same input.
+    fn generate_input(rng: &mut Rng, size: usize) -> String;
+}
+
+/// The input made up from the seed.
+pub fn generate<G: Generator>(seed: u64, size: usize) -> String {
+    G::generate_input(&mut Rng::new(seed), size)
+}
+
+/// An input of a [Streaming] solution made up of the given number of pieces.
+pub fn streamed_input<S: Streaming>(rng: &mut Rng, pieces: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..pieces {
+        S::generate(rng, &mut input);
+    }
+    S::generate_end(&mut input);
+    input
+}
+
+/// Panics unless the inputs made up from a few seeds parse and the given parts solve them, meant to
+/// be called from a test.
+pub fn check_generator<G: Generator>(size: usize, parts: &[u8]) {
+    for seed in 1..=3 {
+        let text = generate::<G>(seed, size);
+        assert_eq!(text, generate::<G>(seed, size), "made up input {seed} depends on more than the seed");
+        let rows = text.lines().map(String::from).collect::<Vec<_>>();
+        let input = G::parse(&rows)
+            .unwrap_or_else(|err| panic!("made up input {seed} of size {size}: {}", err.diagnostic(&rows)));
+        for &part in parts {
+            // the panic itself is shown already, this tells which input caused it
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| -> Answer {
+                if part == 1 { G::part1(&input).into() } else { G::part2(&input).into() }
+            }));
+            if solved.is_err() {
+                panic!("part {part} failed on made up input {seed} of size {size}:\n{text}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{lines, ParseError};
+
+    use super::*;
+
+    struct Digits;
+
+    impl Solution for Digits {
+        type Input = Vec<u8>;
+        type Part1 = u8;
+        type Part2 = u8;
+
+        fn parse(rows: &Vec<String>) -> Result<Vec<u8>, ParseError> {
+            lines(rows).map(|row| row.parse()).collect()
+        }
+
+        fn part1(input: &Vec<u8>) -> u8 {
+            input.len() as u8
+        }
+
+        fn part2(input: &Vec<u8>) -> u8 {
+            input.iter().try_fold(0u8, |sum, &n| sum.checked_add(n)).expect("The sum fits")
+        }
+    }
+
+    impl Generator for Digits {
+        const SIZE: usize = 5;
+
+        fn generate_input(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.range(0..=9))).collect()
+        }
+    }
+
+    #[test]
+    fn checks_made_up_inputs() {
+        assert_eq!(generate::<Digits>(1, 3).lines().count(), 3);
+        check_generator::<Digits>(Digits::SIZE, &[1, 2]);
+        // the sum doesn't fit
+        assert!(panic::catch_unwind(|| check_generator::<Digits>(100, &[2])).is_err());
+    }
+}
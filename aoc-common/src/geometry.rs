@@ -0,0 +1,213 @@
+//! Points on the plane and in space, which double as the vectors between them.
+//!
+//! The plane follows the screen, `x` grows to the right and `y` grows downwards, so that rows of
+//! the input are `y` and the characters within them are `x`.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The number of king moves between both points.
+    pub fn chebyshev(self, other: Point2) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// A single step into the direction of the vector, diagonal ones included.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotates by 90° around the origin, clockwise on the screen.
+    pub fn rotate_right(self) -> Point2 {
+        Point2::new(-self.y, self.x)
+    }
+
+    pub fn rotate_left(self) -> Point2 {
+        Point2::new(self.y, -self.x)
+    }
+}
+
+/// As `(row, column)` to address a [Grid](crate::grid::Grid).
+impl From<Point2> for (isize, isize) {
+    fn from(p: Point2) -> (isize, isize) {
+        (p.y as isize, p.x as isize)
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((row, col): (isize, isize)) -> Point2 {
+        Point2::new(col as i64, row as i64)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+
+    /// The points sharing a face with this one.
+    pub fn neighbours(self) -> [Point3; 6] {
+        [(-1, 0, 0), (1, 0, 0), (0, -1, 0), (0, 1, 0), (0, 0, -1), (0, 0, 1)]
+            .map(|(x, y, z)| self + Point3::new(x, y, z))
+    }
+
+    /// The smallest and the largest coordinates of all points, as the corners of a box around them.
+    pub fn bounds(points: impl IntoIterator<Item=Point3>) -> Option<(Point3, Point3)> {
+        points.into_iter().fold(None, |bounds, p| Some(match bounds {
+            None => (p, p),
+            Some((min, max)) => (Point3::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z)),
+                                 Point3::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z))),
+        }))
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident, $($field:ident),+) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, factor: i64) -> $point {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2, x, y);
+impl_ops!(Point3, x, y, z);
+
+/// A heading on the screen, in clockwise order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The step of a single move into this direction.
+    pub fn vector(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_with_points() {
+        let (a, b) = (Point2::new(2, 18), Point2::new(-2, 15));
+        assert_eq!(a - b, Point2::new(4, 3));
+        assert_eq!(a + -b, a - b);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(-1, -1));
+        assert_eq!(b * 2, Point2::new(-4, 30));
+
+        let c = Point3::new(1, 2, 3);
+        assert_eq!(c.neighbours().iter().filter(|n| n.manhattan(c) == 1).count(), 6);
+        assert_eq!(Point3::bounds([c, Point3::new(3, -2, 3)]), Some((Point3::new(1, -2, 3), Point3::new(3, 2, 3))));
+        assert_eq!(Point3::bounds([]), None);
+    }
+
+    #[test]
+    fn turns_around() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        for dir in Direction::ALL {
+            assert_eq!(dir.vector().rotate_right(), dir.turn_right().vector());
+            assert_eq!(dir.vector().rotate_left(), dir.turn_left().vector());
+            assert_eq!(-dir.vector(), dir.reverse().vector());
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! A rectangular map of tiles addressed by signed `(row, column)` coordinates.
+//!
+//! The top left tile is at the origin, `(0, 0)` unless moved with [Grid::with_origin], so that maps
+//! growing into negative coordinates don't need their own offset bookkeeping. Lookups outside of the
+//! map return `None` instead of panicking, which covers the bounds checks when stepping to neighbours.
+//! Anything convertible into `(row, column)` is accepted as well, like a [Point2](crate::geometry::Point2)
+//! with `x` as the column.
+
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Token};
+
+/// Up, left, right and down.
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The 4 neighbours and the diagonal ones, row by row.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    origin: (isize, isize),
+    tiles: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Grid<T> {
+        Grid { width, height, origin: (0, 0), tiles: vec![tile; width * height] }
+    }
+
+    /// Like [Grid::parse] but shorter rows are filled up with the padding instead of being an error.
+    pub fn parse_padded<'a>(rows: impl IntoIterator<Item=Token<'a>>, padding: T,
+                            mut tile: impl FnMut(Token<'a>) -> Result<T, ParseError>) -> Result<Grid<T>, ParseError> {
+        let mut tiles = vec!();
+        for row in rows {
+            tiles.push(row.chars().map(&mut tile).collect::<Result<Vec<_>, _>>()?);
+        }
+        let width = tiles.iter().map(Vec::len).max().unwrap_or(0);
+        tiles.iter_mut().for_each(|row| row.resize(width, padding.clone()));
+        Ok(Grid::from_rows(tiles))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics unless all rows have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == width), "All rows of a grid have the same length");
+        Grid { width, height: rows.len(), origin: (0, 0), tiles: rows.into_iter().flatten().collect() }
+    }
+
+    /// A map with a tile for each character, all rows need to be of the same width.
+    pub fn parse<'a>(rows: impl IntoIterator<Item=Token<'a>>,
+                     mut tile: impl FnMut(Token<'a>) -> Result<T, ParseError>) -> Result<Grid<T>, ParseError> {
+        let mut grid = Grid { width: 0, height: 0, origin: (0, 0), tiles: vec!() };
+        for row in rows {
+            let width = row.chars().count();
+            if grid.height == 0 {
+                grid.width = width;
+            } else if width != grid.width {
+                return Err(row.error(format!("expected a row of {} tiles", grid.width)));
+            }
+            for token in row.chars() {
+                grid.tiles.push(tile(token)?);
+            }
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    /// Moves the map so that its top left tile is at the given coordinates.
+    pub fn with_origin(self, origin: (isize, isize)) -> Grid<T> {
+        Grid { origin, ..self }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> (isize, isize) {
+        self.origin
+    }
+
+    fn offset(&self, pos: impl Into<(isize, isize)>) -> Option<usize> {
+        let (row, col) = pos.into();
+        let (row, col) = (row - self.origin.0, col - self.origin.1);
+        if row < 0 || col < 0 || row as usize >= self.height || col as usize >= self.width {
+            return None;
+        }
+        Some(row as usize * self.width + col as usize)
+    }
+
+    pub fn contains(&self, pos: impl Into<(isize, isize)>) -> bool {
+        self.offset(pos).is_some()
+    }
+
+    pub fn get(&self, pos: impl Into<(isize, isize)>) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.tiles[offset])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<(isize, isize)>) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.tiles[offset])
+    }
+
+    /// The coordinates within the map when leaving it on one side comes back in on the opposite one.
+    pub fn wrap(&self, pos: impl Into<(isize, isize)>) -> (isize, isize) {
+        let (row, col) = pos.into();
+        assert!(self.width > 0 && self.height > 0, "Only a map with tiles can be wrapped around");
+        ((row - self.origin.0).rem_euclid(self.height as isize) + self.origin.0,
+         (col - self.origin.1).rem_euclid(self.width as isize) + self.origin.1)
+    }
+
+    pub fn get_wrapping(&self, pos: impl Into<(isize, isize)>) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// All coordinates of the map, row by row.
+    pub fn positions(&self) -> impl Iterator<Item=(isize, isize)> {
+        let (width, origin) = (self.width, self.origin);
+        (0..self.width * self.height).map(move |idx| ((idx / width) as isize + origin.0, (idx % width) as isize + origin.1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=((isize, isize), &T)> {
+        self.positions().zip(self.tiles.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.tiles.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// The neighbours which are within the map, either of the steps in [NEIGHBOURS4] or [NEIGHBOURS8].
+    pub fn neighbours<'a>(&'a self, pos: impl Into<(isize, isize)>, steps: &'a [(isize, isize)])
+                          -> impl Iterator<Item=(isize, isize)> + 'a {
+        let (row, col) = pos.into();
+        steps.iter()
+            .map(move |step| (row + step.0, col + step.1))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn neighbours4(&self, pos: impl Into<(isize, isize)>) -> impl Iterator<Item=(isize, isize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS4)
+    }
+
+    pub fn neighbours8(&self, pos: impl Into<(isize, isize)>) -> impl Iterator<Item=(isize, isize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, origin: self.origin, tiles: self.tiles.iter().map(f).collect() }
+    }
+
+    /// Adds a row at the bottom, panics unless it has the width of the map.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item=T>) {
+        let len = self.tiles.len();
+        self.tiles.extend(row);
+        assert_eq!(self.tiles.len() - len, self.width, "All rows of a grid have the same length");
+        self.height += 1;
+    }
+
+    /// The map as text, a line for each row.
+    pub fn render(&self, tile: impl Fn(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&tile).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T, P: Into<(isize, isize)>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: P) -> &T {
+        let pos = pos.into();
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} is outside of the map"))
+    }
+}
+
+impl<T, P: Into<(isize, isize)>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, pos: P) -> &mut T {
+        let pos = pos.into();
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} is outside of the map"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Point2;
+    use crate::parse::lines;
+
+    use super::*;
+
+    fn rows(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    fn tile(token: Token) -> Result<bool, ParseError> {
+        match token.text {
+            "#" => Ok(true),
+            "." => Ok(false),
+            _ => Err(token.error("expected # or .")),
+        }
+    }
+
+    #[test]
+    fn parses_and_renders_maps() {
+        let rows = rows("#..\n.#.\n..#\n##.");
+        let grid = Grid::parse(lines(&rows), tile).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get((1, 1)), Some(&true));
+        assert_eq!(grid.get((3, 2)), Some(&false));
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.iter().filter(|(_, &rock)| rock).count(), 5);
+        assert_eq!(grid.render(|&rock| if rock { '#' } else { '.' }), rows.join("\n"));
+
+        let err = Grid::parse(lines(&self::rows("#..\n.#")), tile).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected a row of 3 tiles, found \".#\"");
+        let err = Grid::parse(lines(&self::rows("#.x")), tile).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 3: expected # or ., found \"x\"");
+
+        let grid = Grid::parse_padded(lines(&self::rows("..#\n#")), false, tile).unwrap();
+        assert_eq!(grid.render(|&rock| if rock { '#' } else { '.' }), "..#\n#..");
+    }
+
+    #[test]
+    fn steps_within_the_map() {
+        let mut grid = Grid::new(3, 2, 0).with_origin((-1, -1));
+        grid[(-1, -1)] = 1;
+        grid[(0, 1)] = 2;
+        assert_eq!(grid.positions().next(), Some((-1, -1)));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 0, 0], [0, 0, 2]]);
+        assert_eq!(grid[Point2::new(1, 0)], 2);
+
+        assert_eq!(grid.neighbours4((-1, -1)).collect::<Vec<_>>(), [(-1, 0), (0, -1)]);
+        assert_eq!(grid.neighbours8((0, 0)).collect::<Vec<_>>(), [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1)]);
+
+        assert_eq!(grid.wrap((1, -2)), (-1, 1));
+        assert_eq!(grid.get_wrapping((-2, 4)), &2);
+        assert_eq!(grid.get_wrapping((0, -4)), &0);
+
+        grid.push_row([3, 3, 3]);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.map(|&n| n * 2)[(1, 1)], 6);
+    }
+}
@@ -0,0 +1,108 @@
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::parse::{lines, Token};
+
+/// Reads all lines of the given file without their line endings.
+pub fn readlines<P: AsRef<Path>>(filename: P) -> io::Result<Vec<String>> {
+    let file = BufReader::new(File::open(filename)?);
+    file.lines().collect()
+}
+
+/// Where an input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    /// Written as `-`, e.g. to pipe generated inputs through a solution.
+    Stdin,
+    /// The input itself instead of a file.
+    Text(String),
+}
+
+impl Source {
+    /// Reads all lines of the input without their line endings, stdin can only be read once.
+    pub fn read(&self) -> io::Result<Vec<String>> {
+        match self {
+            Source::File(path) => readlines(path),
+            Source::Stdin => io::stdin().lock().lines().collect(),
+            Source::Text(text) => Ok(text.lines().map(String::from).collect()),
+        }
+    }
+}
+
+/// A path, `-` is stdin.
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Quoted paths, to name the input in messages.
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "\"{}\"", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Text(_) => write!(f, "the input text"),
+        }
+    }
+}
+
+/// Splits the rows into the blocks separated by blank lines, empty blocks are skipped.
+pub fn blocks(rows: &[String]) -> Vec<Vec<Token<'_>>> {
+    let mut blocks = vec!(vec!());
+    for line in lines(rows) {
+        if line.text.trim().is_empty() {
+            blocks.push(vec!());
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn blocks_are_split_at_blank_lines() {
+        let rows = rows("1\n2\n\n3\n\n\n4\n5\n6\n\n");
+        let blocks = blocks(&rows);
+        let texts = blocks.iter()
+            .map(|block| block.iter().map(|line| line.text).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(texts, [vec!["1", "2"], vec!["3"], vec!["4", "5", "6"]]);
+        assert_eq!(blocks[2][0].line, 7);
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        assert_eq!(readlines("does/not/exist").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn reads_sources() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        let file: Source = "examples/test.in".parse().unwrap();
+        assert_eq!(file, Source::File(PathBuf::from("examples/test.in")));
+        assert_eq!(file.to_string(), "\"examples/test.in\"");
+        assert_eq!(Source::Text("1\n\n2\n".to_string()).read().unwrap(), ["1", "", "2"]);
+        assert_eq!(Source::File(PathBuf::from("does/not/exist")).read().unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}
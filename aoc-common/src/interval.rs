@@ -0,0 +1,186 @@
+//! Sets of integers kept as the inclusive ranges they consist of, for when there are far too many
+//! numbers to keep each of them.
+
+use std::ops::RangeInclusive;
+
+/// The ranges are kept sorted and merged, ranges which overlap or touch become a single one.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet { ranges: vec!() }
+    }
+
+    /// Adds all numbers of the range, an empty range changes nothing.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // all ranges from lo to hi overlap or touch the new one
+        let lo = self.ranges.partition_point(|&(_, e)| e < start.saturating_sub(1));
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if lo < hi {
+            start = start.min(self.ranges[lo].0);
+            end = end.max(self.ranges[hi - 1].1);
+        }
+        self.ranges.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Removes all numbers of the range.
+    pub fn remove(&mut self, range: RangeInclusive<i64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let lo = self.ranges.partition_point(|&(_, e)| e < start);
+        let hi = self.ranges.partition_point(|&(s, _)| s <= end);
+        if lo == hi {
+            return;
+        }
+        // what is left of the first and the last range sticking out of the removed one
+        let mut rest = vec!();
+        if self.ranges[lo].0 < start {
+            rest.push((self.ranges[lo].0, start - 1));
+        }
+        if self.ranges[hi - 1].1 > end {
+            rest.push((end + 1, self.ranges[hi - 1].1));
+        }
+        self.ranges.splice(lo..hi, rest);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        other.ranges().for_each(|range| union.insert(range));
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intersection = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                intersection.ranges.push((start, end));
+            }
+            // the range ending first can't overlap any of the following ones
+            if a.1 < b.1 { i += 1 } else { j += 1 }
+        }
+        intersection
+    }
+
+    /// The numbers of this set which aren't in the other one.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        other.ranges().for_each(|range| difference.remove(range));
+        difference
+    }
+
+    /// The numbers within the given range which aren't in the set.
+    pub fn gaps(&self, within: RangeInclusive<i64>) -> IntervalSet {
+        IntervalSet::from(within).difference(self)
+    }
+
+    /// How many numbers the set holds.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|&(start, end)| end.abs_diff(start) + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.contains_range(value..=value)
+    }
+
+    /// Whether all numbers of the range are in the set, which is true for an empty range.
+    pub fn contains_range(&self, range: RangeInclusive<i64>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return true;
+        }
+        let idx = self.ranges.partition_point(|&(_, e)| e < start);
+        self.ranges.get(idx).is_some_and(|&(s, e)| s <= start && end <= e)
+    }
+
+    pub fn is_subset(&self, other: &IntervalSet) -> bool {
+        self.ranges().all(|range| other.contains_range(range))
+    }
+
+    /// Whether any number of the range is in the set.
+    pub fn overlaps(&self, range: RangeInclusive<i64>) -> bool {
+        let (start, end) = range.into_inner();
+        let idx = self.ranges.partition_point(|&(_, e)| e < start);
+        start <= end && self.ranges.get(idx).is_some_and(|&(s, _)| s <= end)
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item=RangeInclusive<i64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for IntervalSet {
+    fn from(range: RangeInclusive<i64>) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item=RangeInclusive<i64>>>(ranges: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        ranges.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet) -> Vec<RangeInclusive<i64>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn merges_ranges() {
+        let mut set: IntervalSet = [12..=14, 1..=3, 5..=7, 8..=8, 2..=4].into_iter().collect();
+        assert_eq!(ranges(&set), [1..=8, 12..=14]);
+        let (start, end) = (20, 19);
+        set.insert(start..=end);
+        assert_eq!(set.len(), 11);
+        set.insert(0..=13);
+        assert_eq!(ranges(&set), [0..=14]);
+
+        set.remove(3..=4);
+        set.remove(14..=20);
+        set.remove(-5..=0);
+        assert_eq!(ranges(&set), [1..=2, 5..=13]);
+        set.remove(2..=5);
+        assert_eq!(ranges(&set), [1..=1, 6..=13]);
+        assert_eq!(set.gaps(-1..=15).ranges().collect::<Vec<_>>(), [-1..=0, 2..=5, 14..=15]);
+
+        assert!(set.contains(1) && set.contains(13) && !set.contains(5));
+        assert!(set.contains_range(7..=10) && !set.contains_range(0..=1) && !set.contains_range(1..=6));
+        assert!(set.overlaps(0..=1) && set.overlaps(5..=6) && !set.overlaps(2..=5) && !set.overlaps(20..=30));
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: IntervalSet = [0..=5, 10..=15, 20..=25].into_iter().collect();
+        let b: IntervalSet = [3..=12, 14..=21].into_iter().collect();
+        assert_eq!(ranges(&a.union(&b)), [0..=25]);
+        assert_eq!(ranges(&a.intersection(&b)), [3..=5, 10..=12, 14..=15, 20..=21]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=2, 13..=13, 22..=25]);
+        assert_eq!(ranges(&b.difference(&a)), [6..=9, 16..=19]);
+        assert!(a.intersection(&b).is_subset(&a) && !a.is_subset(&b));
+    }
+}
@@ -0,0 +1,37 @@
+//! Helpers shared by the solutions of all days: loading and parsing the puzzle input, running the parts
+//! and checking their answers.
+
+pub mod answer;
+pub mod answers;
+pub mod cycle;
+pub mod examples;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod memo;
+pub mod ocr;
+pub mod parallel;
+pub mod parse;
+pub mod reference;
+pub mod rng;
+pub mod search;
+pub mod solution;
+pub mod stream;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use answers::{Answers, Check};
+pub use examples::{check_examples, run_examples};
+pub use generate::Generator;
+pub use geometry::{Direction, Point2, Point3};
+pub use grid::{Grid, NEIGHBOURS4, NEIGHBOURS8};
+pub use input::{blocks, readlines, Source};
+pub use interval::IntervalSet;
+pub use memo::Memo;
+pub use parse::{end_of_input, lines, ParseError, Token, Tokens};
+pub use reference::Reference;
+pub use rng::Rng;
+pub use solution::{Puzzle, Solution};
+pub use stream::{Stream, StreamError, Streaming};
@@ -0,0 +1,140 @@
+//! Memoization for recursive solvers, keyed by whatever describes the state of a call.
+//!
+//! A [Memo] counts how often it could answer from what it stored, and reports it when dropped so
+//! that the runner can show how effective the caching of a part was.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::sync::Mutex;
+
+/// How a memo did, see [take_stats].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub name: &'static str,
+    pub hits: u64,
+    pub misses: u64,
+    /// values stored at the end
+    pub entries: usize,
+}
+
+impl Stats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} hits, {} misses ({:.1}% hits), {} entries",
+               self.name, self.hits, self.misses, 100.0 * self.hit_rate(), self.entries)
+    }
+}
+
+static REPORTED: Mutex<Vec<Stats>> = Mutex::new(Vec::new());
+
+// memos of the same name are summed up, e.g. one for each blueprint
+fn report(stats: Stats) {
+    let mut reported = REPORTED.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match reported.iter_mut().find(|s| s.name == stats.name) {
+        Some(known) => {
+            known.hits += stats.hits;
+            known.misses += stats.misses;
+            known.entries += stats.entries;
+        }
+        None => reported.push(stats),
+    }
+}
+
+/// The statistics of the memos dropped since the last call.
+pub fn take_stats() -> Vec<Stats> {
+    std::mem::take(&mut *REPORTED.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+}
+
+pub struct Memo<K, V> {
+    name: &'static str,
+    values: HashMap<K, V>,
+    capacity: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new(name: &'static str) -> Memo<K, V> {
+        Memo { name, values: HashMap::new(), capacity: None, hits: 0, misses: 0 }
+    }
+
+    /// Stores at most `capacity` values, once it is full the others are computed every time they are needed.
+    pub fn bounded(name: &'static str, capacity: usize) -> Memo<K, V> {
+        Memo { name, values: HashMap::new(), capacity: Some(capacity), hits: 0, misses: 0 }
+    }
+
+    /// The stored value of the key, else computes and stores it. The computation gets the memo
+    /// passed on for the recursive calls.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+
+        let value = compute(self);
+        if self.capacity.is_none_or(|capacity| self.values.len() < capacity) {
+            self.values.insert(key, value.clone());
+        }
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats { name: self.name, hits: self.hits, misses: self.misses, entries: self.values.len() }
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if self.hits + self.misses > 0 {
+            report(Stats { name: self.name, hits: self.hits, misses: self.misses, entries: self.values.len() });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn remembers_values() {
+        let mut memo = Memo::new("fibonacci");
+        assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+        assert_eq!(memo.stats(), Stats { name: "fibonacci", hits: 87, misses: 89, entries: 89 });
+        assert_eq!(memo.stats().to_string(), "fibonacci: 87 hits, 89 misses (49.4% hits), 89 entries");
+
+        let mut memo = Memo::bounded("bounded fibonacci", 10);
+        assert_eq!(fibonacci(&mut memo, 30), 832040);
+        assert_eq!(memo.len(), 10);
+        drop(memo);
+        drop(Memo::<u64, u64>::new("unused"));
+
+        let stats = take_stats();
+        assert!(stats.iter().all(|s| s.name != "unused"));
+        assert!(stats.iter().any(|s| s.name == "bounded fibonacci" && s.entries == 10));
+    }
+}
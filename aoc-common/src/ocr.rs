@@ -0,0 +1,109 @@
+//! Reading the capital letters some puzzles draw with lit pixels instead of answering with text.
+//!
+//! The puzzles use two fonts, letters 6 pixels high (e.g. 2022 day 10) and 10 pixels high (e.g.
+//! 2018 day 10). The letters are told apart by the blank columns between them, so their widths
+//! don't matter.
+
+use crate::grid::Grid;
+
+const FONT_6: [(char, &str); 19] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('T', "###\n.#.\n.#.\n.#.\n.#.\n.#."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const FONT_10: [(char, &str); 15] = [
+    ('A', "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#"),
+    ('B', "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####."),
+    ('C', ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####."),
+    ('E', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('F', "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('G', ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#"),
+    ('H', "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#"),
+    ('J', "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###.."),
+    ('K', "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#"),
+    ('L', "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######"),
+    ('N', "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#"),
+    ('P', "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#....."),
+    ('R', "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#"),
+    ('X', "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#"),
+    ('Z', "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######"),
+];
+
+/// The letters drawn by the pixels, none if any of them isn't known (or it isn't letters at all).
+pub fn read(pixels: &Grid<bool>) -> Option<String> {
+    let font: &[(char, &str)] = match pixels.height() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+    let rows = pixels.rows().collect::<Vec<_>>();
+    let is_blank = |col: usize| rows.iter().all(|row| !row[col]);
+
+    let mut letters = String::new();
+    let mut col = 0;
+    while col < pixels.width() {
+        if is_blank(col) {
+            col += 1;
+            continue;
+        }
+        let end = (col..pixels.width()).find(|&c| is_blank(c)).unwrap_or(pixels.width());
+        let glyph = rows.iter()
+            .map(|row| row[col..end].iter().map(|&lit| if lit { '#' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        letters.push(font.iter().find(|(_, pattern)| *pattern == glyph)?.0);
+        col = end;
+    }
+
+    if letters.is_empty() { None } else { Some(letters) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the patterns of the letters next to each other, separated by a blank column
+    fn draw(font: &[(char, &str)], text: &str) -> Grid<bool> {
+        let glyphs = text.chars()
+            .map(|c| font.iter().find(|(letter, _)| *letter == c).unwrap().1.lines().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let rows = (0..glyphs[0].len())
+            .map(|row| glyphs.iter()
+                .flat_map(|glyph| glyph[row].chars().chain(['.']))
+                .map(|c| c == '#')
+                .collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    #[test]
+    fn reads_letters() {
+        let text = FONT_6.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read(&draw(&FONT_6, &text)), Some(text));
+        let text = FONT_10.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read(&draw(&FONT_10, &text)), Some(text));
+
+        let mut pixels = draw(&FONT_6, "HI");
+        pixels[(0, 1)] = true;
+        assert_eq!(read(&pixels), None);
+        assert_eq!(read(&Grid::new(40, 6, false)), None);
+        assert_eq!(read(&Grid::new(4, 7, true)), None);
+    }
+}
@@ -0,0 +1,148 @@
+//! Spreading independent pieces of work over several threads, set up by the runner with `--threads`.
+//!
+//! The results come in the order of the items no matter which thread computed them, so a solution
+//! gives the same answer with any number of threads.
+
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::panic;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// 0 until set, for as many threads as there are cores
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// How many threads the work is spread over, at least one.
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+        threads => threads,
+    }
+}
+
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+/// Runs the worker on each thread and waits for all of them, a panic of a worker is passed on
+/// unchanged, with its own message rather than one about a thread.
+fn run_workers<R: Send>(workers: usize, worker: impl Fn() -> R + Sync) -> Vec<R> {
+    thread::scope(|scope| {
+        let handles = (0..workers).map(|_| scope.spawn(&worker)).collect::<Vec<_>>();
+        handles.into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+            .collect()
+    })
+}
+
+/// Applies `f` to all items, like `items.iter().map(f).collect()`.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with(items, || (), |_, item| f(item))
+}
+
+/// Applies `f` to all items with a state of each thread, e.g. a [Memo](crate::Memo) shared by the
+/// items a thread takes on.
+pub fn map_with<T: Sync, S, R: Send>(items: &[T],
+                                     init: impl Fn() -> S + Sync,
+                                     f: impl Fn(&mut S, &T) -> R + Sync) -> Vec<R> {
+    map_with_on(threads(), items, init, f)
+}
+
+/// Like [map_with] on the given number of threads rather than the ones set up for all work.
+pub fn map_with_on<T: Sync, S, R: Send>(threads: usize,
+                                        items: &[T],
+                                        init: impl Fn() -> S + Sync,
+                                        f: impl Fn(&mut S, &T) -> R + Sync) -> Vec<R> {
+    let workers = threads.min(items.len());
+    if workers <= 1 {
+        let mut state = init();
+        return items.iter().map(|item| f(&mut state, item)).collect();
+    }
+
+    // the items are handed out one by one as they can take very different times
+    let next = AtomicUsize::new(0);
+    let results = run_workers(workers, || {
+        let mut state = init();
+        let mut results = vec!();
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+            if idx >= items.len() {
+                break results;
+            }
+            results.push((idx, f(&mut state, &items[idx])));
+        }
+    });
+
+    let mut results = results.into_iter().flatten().collect::<Vec<_>>();
+    results.sort_unstable_by_key(|&(idx, _)| idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// The result of the first number within the range `f` finds one for, like
+/// `range.find_map(f)`. The numbers beyond it may be looked at as well.
+pub fn find_first<R: Send>(range: RangeInclusive<i64>, f: impl Fn(i64) -> Option<R> + Sync) -> Option<R> {
+    find_first_on(threads(), range, f)
+}
+
+/// Like [find_first] on the given number of threads rather than the ones set up for all work.
+pub fn find_first_on<R: Send>(threads: usize, range: RangeInclusive<i64>, f: impl Fn(i64) -> Option<R> + Sync) -> Option<R> {
+    let (start, end) = range.into_inner();
+    let workers = threads;
+    if workers <= 1 || start >= end {
+        return (start..=end).find_map(f);
+    }
+
+    // the chunks are handed out in ascending order, so once one is found only the chunks before it
+    // can hold an earlier one
+    let chunk_size = (end.abs_diff(start) / (workers as u64 * 64)).max(1) as i64;
+    let next = AtomicI64::new(start);
+    let found = AtomicI64::new(i64::MAX);
+    let first = Mutex::new(None);
+    run_workers(workers, || {
+        loop {
+            let chunk_start = next.fetch_add(chunk_size, Ordering::Relaxed);
+            if chunk_start > end || chunk_start > found.load(Ordering::Relaxed) {
+                break;
+            }
+            let chunk_end = chunk_start.saturating_add(chunk_size - 1).min(end);
+            if let Some((n, result)) = (chunk_start..=chunk_end).find_map(|n| f(n).map(|result| (n, result))) {
+                found.fetch_min(n, Ordering::Relaxed);
+                let mut first = first.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                if first.as_ref().is_none_or(|&(m, _)| n < m) {
+                    *first = Some((n, result));
+                }
+                break;
+            }
+        }
+    });
+
+    first.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner()).map(|(_, result)| result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_their_order() {
+        let items = (0..1000u64).collect::<Vec<_>>();
+        let squares = items.iter().map(|n| n * n).collect::<Vec<_>>();
+        for threads in [1, 3, 8] {
+            assert_eq!(map_with_on(threads, &items, || (), |_, n| n * n), squares);
+            // each thread counts the items it took on
+            let counts = map_with_on(threads, &items, || 0, |count, _| { *count += 1; *count });
+            assert_eq!(counts.len(), items.len());
+            assert_eq!(find_first_on(threads, -5..=100_000, |n| (n * n > 1000).then_some(n)), Some(32));
+            assert_eq!(find_first_on(threads, 0..=1000, |n| (n > 1000).then_some(n)), None);
+        }
+        assert_eq!(map::<u8, u8>(&[], |&n| n), []);
+    }
+
+    #[test]
+    fn panics_are_passed_on() {
+        let payload = panic::catch_unwind(|| map_with_on(4, &[1, 2, 3, 4], || (), |_, &n| if n == 3 { panic!("unlucky item") } else { n }))
+            .unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"unlucky item"));
+    }
+}
@@ -0,0 +1,213 @@
+//! Parsing the puzzle input with errors that point to the offending part of it.
+
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line within the input
+    pub line: usize,
+    /// 1-based column of the offending token
+    pub column: usize,
+    pub token: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// The error followed by the offending line of the input with the token underlined.
+    pub fn diagnostic(&self, rows: &[String]) -> String {
+        match self.line.checked_sub(1).and_then(|idx| rows.get(idx)) {
+            Some(row) => self.diagnostic_of_row(row),
+            None => self.to_string(),
+        }
+    }
+
+    /// The error followed by the given row, the offending one, with the token underlined.
+    pub fn diagnostic_of_row(&self, row: &str) -> String {
+        format!("{self}\n  {row}\n  {}{}", " ".repeat(self.column.saturating_sub(1)), "^".repeat(self.token.len().max(1)))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.reason)?;
+        if !self.token.is_empty() {
+            write!(f, ", found \"{}\"", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A part of the input which remembers where it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Token<'a> {
+    pub fn error(&self, reason: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            token: self.text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// The empty token right behind this one, to point at something missing.
+    pub fn end(&self) -> Token<'a> {
+        Token { line: self.line, column: self.column + self.text.len(), text: "" }
+    }
+
+    fn sub(&self, start: usize, end: usize) -> Token<'a> {
+        Token { line: self.line, column: self.column + start, text: &self.text[start..end] }
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        let name = type_name::<T>().rsplit("::").next().unwrap();
+        self.text.parse().map_err(|_| self.error(format!("expected {name}")))
+    }
+
+    pub fn split(&self, separator: char) -> Tokens<'a> {
+        let mut tokens = vec!();
+        let mut start = 0;
+        for (idx, _) in self.text.match_indices(separator) {
+            tokens.push(self.sub(start, idx));
+            start = idx + separator.len_utf8();
+        }
+        tokens.push(self.sub(start, self.text.len()));
+        Tokens { source: *self, tokens }
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Token<'a>, Token<'a>), ParseError> {
+        match self.text.find(separator) {
+            Some(idx) => Ok((self.sub(0, idx), self.sub(idx + separator.len(), self.text.len()))),
+            None => Err(self.error(format!("expected \"{separator}\""))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.starts_with(prefix) {
+            true => Ok(self.sub(prefix.len(), self.text.len())),
+            false => Err(self.error(format!("expected \"{prefix}\""))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Token<'a>, ParseError> {
+        match self.text.ends_with(suffix) {
+            true => Ok(self.sub(0, self.text.len() - suffix.len())),
+            false => Err(self.error(format!("expected \"{suffix}\""))),
+        }
+    }
+
+    pub fn trim(&self) -> Token<'a> {
+        self.trim_matches(char::is_whitespace)
+    }
+
+    pub fn trim_matches<P: Fn(char) -> bool + Copy>(&self, pattern: P) -> Token<'a> {
+        let start = self.text.len() - self.text.trim_start_matches(pattern).len();
+        let end = self.text.trim_end_matches(pattern).len().max(start);
+        self.sub(start, end)
+    }
+
+    /// Fails unless the token is exactly the expected text.
+    pub fn expect(&self, expected: &str) -> Result<(), ParseError> {
+        match self.text == expected {
+            true => Ok(()),
+            false => Err(self.error(format!("expected \"{expected}\""))),
+        }
+    }
+
+    /// Every character as a token of its own.
+    pub fn chars(self) -> impl Iterator<Item=Token<'a>> {
+        self.text.char_indices().map(move |(idx, c)| self.sub(idx, idx + c.len_utf8()))
+    }
+}
+
+/// The tokens a [Token] got split into.
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    source: Token<'a>,
+    tokens: Vec<Token<'a>>,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn get(&self, idx: usize) -> Result<Token<'a>, ParseError> {
+        self.tokens.get(idx).copied()
+            .ok_or_else(|| self.source.end().error(format!("expected at least {} tokens", idx + 1)))
+    }
+
+    pub fn parse<T: FromStr>(&self, idx: usize) -> Result<T, ParseError> {
+        self.get(idx)?.parse()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=Token<'a>> + '_ {
+        self.tokens.iter().copied()
+    }
+}
+
+/// An empty token behind the last row, to point at something missing at the end of the input.
+pub fn end_of_input(rows: &[String]) -> Token<'_> {
+    Token { line: rows.len() + 1, column: 1, text: "" }
+}
+
+/// The rows of the input as tokens, numbered from 1.
+pub fn lines(rows: &[String]) -> impl Iterator<Item=Token<'_>> {
+    rows.iter().enumerate().map(|(idx, row)| Token { line: idx + 1, column: 1, text: row })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn tokens_know_their_position() {
+        let rows = rows("first line\nmove 3 from x=-1, y=2");
+        let line = lines(&rows).nth(1).unwrap();
+        let tokens = line.split(' ');
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens.parse::<u32>(1), Ok(3));
+
+        let x = tokens.get(3).unwrap().strip_prefix("x=").unwrap().trim_matches(|c| c == ',');
+        assert_eq!(x, Token { line: 2, column: 15, text: "-1" });
+        assert_eq!(x.parse::<i32>(), Ok(-1));
+
+        let (key, value) = tokens.get(4).unwrap().split_once("=").unwrap();
+        assert_eq!((key.column, value.column), (19, 21));
+        assert_eq!(line.chars().nth(5).unwrap(), Token { line: 2, column: 6, text: "3" });
+    }
+
+    #[test]
+    fn errors_point_to_the_token() {
+        let rows = rows("move 3 from 1 to x");
+        let tokens = lines(&rows).next().unwrap().split(' ');
+
+        let err = tokens.parse::<usize>(5).unwrap_err();
+        assert_eq!(err, ParseError { line: 1, column: 18, token: "x".to_string(), reason: "expected usize".to_string() });
+        assert_eq!(err.to_string(), "line 1, column 18: expected usize, found \"x\"");
+        assert_eq!(err.diagnostic(&rows), "line 1, column 18: expected usize, found \"x\"\n  move 3 from 1 to x\n                   ^");
+
+        let err = tokens.get(6).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 19: expected at least 7 tokens");
+        assert!(tokens.get(0).unwrap().expect("mov").is_err());
+        assert!(tokens.get(0).unwrap().strip_suffix("ve").is_ok());
+    }
+}
@@ -0,0 +1,244 @@
+//! Naive counterparts of clever parts, to compare them on made up inputs.
+//!
+//! A shortcut like extrapolating a cycle or skipping steps is easy to break in a way the examples
+//! don't catch. A [Reference] solves the same puzzle the slow and simple way, on inputs small enough
+//! for that, and [compare] reports the first made up input they disagree on, cut down to as little as
+//! still shows the disagreement.
+
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+use std::panic;
+use std::sync::Once;
+
+use crate::answer::Answer;
+use crate::generate::Generator;
+use crate::rng::Rng;
+use crate::solution::catch_panic;
+
+/// A [Generator] whose parts have naive counterparts.
+pub trait Reference: Generator {
+    /// The parts which have a naive counterpart.
+    const PARTS: &'static [u8];
+    /// The size of the inputs which the naive parts still solve quickly, see [Generator::SIZE].
+    const REFERENCE_SIZE: usize;
+
+    /// An input for the naive parts, the made up one unless the puzzle itself has to shrink, like the
+    /// area a scan goes over.
+    fn reference_input(rng: &mut Rng, size: usize) -> String {
+        Self::generate_input(rng, size)
+    }
+
+    /// The answer of the part, on the puzzle shrunk the same way as for [Reference::naive], e.g. fewer
+    /// rocks falling.
+    fn clever(input: &Self::Input, part: u8) -> Answer {
+        if part == 1 { Self::part1(input).into() } else { Self::part2(input).into() }
+    }
+
+    /// The answer of the part found the slow and simple way.
+    fn naive(input: &Self::Input, part: u8) -> Answer;
+}
+
+/// A made up input a part answers differently than its naive counterpart.
+#[derive(Debug, PartialEq)]
+pub struct Disagreement {
+    pub seed: u64,
+    pub part: u8,
+    /// The made up input cut down to as few lines, and characters within them, as still disagree.
+    pub input: String,
+    /// What the part came up with on the cut down input, or why it failed.
+    pub answer: Result<Answer, String>,
+    /// What the naive part came up with on the cut down input.
+    pub expected: Answer,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Ok(answer) => write!(f, "Part {} answered {answer} instead of {}", self.part, self.expected)?,
+            Err(reason) => write!(f, "Part {} failed instead of answering {}: {reason}", self.part, self.expected)?,
+        }
+        write!(f, " on made up input {}, cut down to:\n{}", self.seed, self.input)
+    }
+}
+
+/// What the part and its naive counterpart answer, none if the input doesn't parse or the naive part
+/// fails on it as well.
+fn answers<R: Reference>(text: &str, part: u8) -> Option<(Result<Answer, String>, Answer)> {
+    let rows = text.lines().map(String::from).collect();
+    let input = R::parse(&rows).ok()?;
+    let expected = catch_panic(|| R::naive(&input, part)).ok()?;
+    Some((catch_panic(|| R::clever(&input, part)), expected))
+}
+
+/// Leaves out chunks of the items for as long as the rest is still interesting, the chunks halve
+/// down to single items.
+fn shrink<T: Clone>(items: &mut Vec<T>, mut interesting: impl FnMut(&[T]) -> bool) {
+    let mut chunk = items.len().div_ceil(2);
+    while chunk > 0 {
+        let mut start = 0;
+        while start < items.len() {
+            let rest = [&items[..start], &items[(start + chunk).min(items.len())..]].concat();
+            if interesting(&rest) {
+                *items = rest;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+}
+
+/// The text cut down to as few lines, and then characters within them, as are still interesting.
+fn minimise(text: &str, interesting: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    shrink(&mut lines, |lines| interesting(&join(lines)));
+
+    for idx in 0..lines.len() {
+        let mut chars = lines[idx].chars().collect::<Vec<_>>();
+        let mut others = lines.clone();
+        shrink(&mut chars, |chars| {
+            others[idx] = chars.iter().collect();
+            interesting(&join(&others))
+        });
+        lines[idx] = chars.into_iter().collect();
+    }
+    join(&lines)
+}
+
+thread_local! {
+    // whether the panics of this thread go unreported
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keeps the panics of the current thread quiet until it is dropped, also when a panic unwinds past
+/// it. The panic hook is wrapped once, the panics of other threads still get to the previous one.
+struct QuietPanics;
+
+impl QuietPanics {
+    fn new() -> QuietPanics {
+        static WRAP_HOOK: Once = Once::new();
+        WRAP_HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| if !QUIET.get() { hook(info) }));
+        });
+        QUIET.set(true);
+        QuietPanics
+    }
+}
+
+impl Drop for QuietPanics {
+    fn drop(&mut self) {
+        QUIET.set(false);
+    }
+}
+
+/// Compares the part with its naive counterpart on the input made up from the seed, and if they
+/// disagree cuts the input down. Panics if the naive part fails on the made up input.
+pub fn compare<R: Reference>(seed: u64, size: usize, part: u8) -> Option<Disagreement> {
+    let text = R::reference_input(&mut Rng::new(seed), size);
+    let (answer, expected) = answers::<R>(&text, part)
+        .unwrap_or_else(|| panic!("The naive part {part} can't solve made up input {seed} of size {size}"));
+    if answer.as_ref() == Ok(&expected) {
+        return None;
+    }
+
+    // cutting down the input makes the part fail over and over, the reason is kept anyway
+    let quiet = QuietPanics::new();
+    // a wrong answer mustn't turn into a failure or the other way round, that would be another bug
+    let input = minimise(&text, |text| answers::<R>(text, part)
+        .is_some_and(|(cut_answer, expected)| cut_answer != Ok(expected) && cut_answer.is_ok() == answer.is_ok()));
+    drop(quiet);
+
+    let (answer, expected) = answers::<R>(&input, part).expect("The cut down input still disagrees");
+    Some(Disagreement { seed, part, input, answer, expected })
+}
+
+/// Panics with the first disagreement on the inputs made up from the seeds, meant to be called from a
+/// test.
+pub fn check_reference<R: Reference>(size: usize, seeds: RangeInclusive<u64>) {
+    for seed in seeds {
+        for &part in R::PARTS {
+            if let Some(disagreement) = compare::<R>(seed, size, part) {
+                panic!("{disagreement}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{lines, ParseError};
+    use crate::solution::Solution;
+
+    use super::*;
+
+    // sums the numbers, but gets it wrong as soon as one is above 5
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(rows: &Vec<String>) -> Result<Vec<u32>, ParseError> {
+            lines(rows).map(|row| row.parse()).collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> u32 {
+            input.iter().map(|&n| n.min(5)).sum()
+        }
+
+        fn part2(input: &Vec<u32>) -> u32 {
+            input.iter().map(|&n| n.min(5)).sum()
+        }
+    }
+
+    impl Generator for Sum {
+        const SIZE: usize = 10;
+
+        fn generate_input(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.range(0..=99))).collect()
+        }
+    }
+
+    impl Reference for Sum {
+        const PARTS: &'static [u8] = &[1];
+        const REFERENCE_SIZE: usize = 10;
+
+        fn naive(input: &Vec<u32>, _: u8) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+    }
+
+    #[test]
+    fn minimises_disagreements() {
+        assert_eq!(compare::<Sum>(1, 0, 1), None);
+        let disagreement = compare::<Sum>(1, Sum::SIZE, 1).unwrap();
+        // leaving out either digit gets it right
+        assert_eq!(disagreement.input, "50\n");
+        assert_eq!(disagreement.answer, Ok(Answer::Integer(5)));
+        assert_eq!(disagreement.expected, Answer::Integer(50));
+        assert_eq!(disagreement.to_string(), "Part 1 answered 5 instead of 50 on made up input 1, cut down to:\n50\n");
+        assert!(panic::catch_unwind(|| check_reference::<Sum>(Sum::SIZE, 1..=2)).is_err());
+    }
+
+    #[test]
+    fn panics_are_quiet_while_cutting_down() {
+        assert!(panic::catch_unwind(|| {
+            let _quiet = QuietPanics::new();
+            assert!(QUIET.get());
+            panic!("cut down too far");
+        }).is_err());
+        assert!(!QUIET.get());
+    }
+
+    #[test]
+    fn shrinks_to_what_is_interesting() {
+        let mut items = (0..20).collect::<Vec<_>>();
+        shrink(&mut items, |items| items.contains(&3) && items.contains(&17));
+        assert_eq!(items, vec!(3, 17));
+        assert_eq!(minimise("ab\ncd\nef\n", |text| text.contains('d')), "d\n");
+    }
+}
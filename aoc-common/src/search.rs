@@ -0,0 +1,242 @@
+//! Shortest paths through a state space which is only described by the neighbours of each state.
+//!
+//! All searches start from one or more states and stop at the first goal they reach, a goal which
+//! never matches explores everything reachable. What they found is kept in [Paths].
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The distances of the states reached by a search and the way to each of them.
+#[derive(Clone, Debug)]
+pub struct Paths<N> {
+    distances: HashMap<N, u64>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new() -> Paths<N> {
+        Paths { distances: HashMap::new(), predecessors: HashMap::new(), goal: None }
+    }
+
+    /// The goal the search stopped at, if it reached one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The distance to the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// The states from the start the node was reached from up to the node itself.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec!(node.clone());
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All states the search reached with their distances.
+    pub fn iter(&self) -> impl Iterator<Item=(&N, u64)> {
+        self.distances.iter().map(|(node, &distance)| (node, distance))
+    }
+}
+
+/// Breadth-first search, for when every step costs the same.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item=N>,
+                 mut neighbours: impl FnMut(&N) -> I,
+                 mut is_goal: impl FnMut(&N) -> bool) -> Paths<N>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=N>
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let distance = paths.distances[&node];
+        for next in neighbours(&node) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, the neighbours come with the cost of getting to them.
+pub fn dijkstra<N, I>(starts: impl IntoIterator<Item=N>,
+                      neighbours: impl FnMut(&N) -> I,
+                      is_goal: impl FnMut(&N) -> bool) -> Paths<N>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=(N, u64)>
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+// ordered by the lowest estimate first, equal ones in the order they were found to stay deterministic
+struct Entry<N> {
+    estimate: u64,
+    seq: usize,
+    distance: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.estimate, other.seq).cmp(&(self.estimate, self.seq))
+    }
+}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search, the heuristic must never overestimate the remaining distance to a goal.
+pub fn astar<N, I>(starts: impl IntoIterator<Item=N>,
+                   mut neighbours: impl FnMut(&N) -> I,
+                   mut heuristic: impl FnMut(&N) -> u64,
+                   mut is_goal: impl FnMut(&N) -> bool) -> Paths<N>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=(N, u64)>
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::new();
+    let mut seq = 0;
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            heap.push(Entry { estimate: heuristic(&start), seq, distance: 0, node: start });
+            seq += 1;
+        }
+    }
+
+    while let Some(Entry { distance, node, .. }) = heap.pop() {
+        // nodes are added again when a shorter way to them is found, the outdated entries are skipped
+        if distance > paths.distances[&node] {
+            continue;
+        }
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if paths.distances.get(&next).is_none_or(|&known| next_distance < known) {
+                paths.distances.insert(next.clone(), next_distance);
+                paths.predecessors.insert(next.clone(), node.clone());
+                heap.push(Entry { estimate: next_distance + heuristic(&next), seq, distance: next_distance, node: next });
+                seq += 1;
+            }
+        }
+    }
+
+    paths
+}
+
+/// The distances between all pairs of the nodes `0..count`, using Floyd–Warshall.
+pub fn all_pairs<I>(count: usize, mut neighbours: impl FnMut(usize) -> I) -> Vec<Vec<Option<u64>>>
+    where I: IntoIterator<Item=(usize, u64)>
+{
+    let mut distances = vec![vec![None; count]; count];
+    for from in 0..count {
+        distances[from][from] = Some(0);
+        for (to, cost) in neighbours(from) {
+            distances[from][to] = Some(cost.min(distances[from][to].unwrap_or(u64::MAX)));
+        }
+    }
+
+    for k in 0..count {
+        for i in 0..count {
+            let Some(via) = distances[i][k] else { continue };
+            for j in 0..count {
+                if let Some(rest) = distances[k][j] {
+                    if distances[i][j].is_none_or(|d| via + rest < d) {
+                        distances[i][j] = Some(via + rest);
+                    }
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //  0 -1- 1 -1- 2
+    //  |           |
+    //  5           1
+    //  |           |
+    //  4 ----1---- 3    5 (unreachable)
+    fn edges(node: usize) -> Vec<(usize, u64)> {
+        match node {
+            0 => vec!((1, 1), (4, 5)),
+            1 => vec!((0, 1), (2, 1)),
+            2 => vec!((1, 1), (3, 1)),
+            3 => vec!((2, 1), (4, 1)),
+            4 => vec!((0, 5), (3, 1)),
+            _ => vec!(),
+        }
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let paths = bfs([0], |&n| edges(n).into_iter().map(|(next, _)| next), |&n| n == 4);
+        assert_eq!(paths.goal(), Some(&4));
+        assert_eq!(paths.goal_distance(), Some(1));
+        assert_eq!(paths.path(&4), Some(vec!(0, 4)));
+
+        let paths = dijkstra([0], |&n| edges(n), |&n| n == 4);
+        assert_eq!(paths.goal_distance(), Some(4));
+        assert_eq!(paths.path(&4), Some(vec!(0, 1, 2, 3, 4)));
+
+        let paths = astar([0], |&n| edges(n), |&n| [4, 3, 2, 1, 0, 0][n], |&n| n == 4);
+        assert_eq!(paths.path(&4), Some(vec!(0, 1, 2, 3, 4)));
+
+        let paths = dijkstra([0, 4], |&n| edges(n), |_| false);
+        assert_eq!(paths.goal(), None);
+        assert_eq!(paths.iter().count(), 5);
+        assert_eq!(paths.distance(&2), Some(2));
+        assert_eq!(paths.distance(&5), None);
+        assert_eq!(paths.path(&3), Some(vec!(4, 3)));
+    }
+
+    #[test]
+    fn finds_all_pairs() {
+        let distances = all_pairs(6, edges);
+        assert_eq!(distances[0][4], Some(4));
+        assert_eq!(distances[4][1], Some(3));
+        assert_eq!(distances[2][2], Some(0));
+        assert_eq!(distances[5][0], None);
+        assert_eq!(distances[0][5], None);
+    }
+}
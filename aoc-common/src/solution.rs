@@ -0,0 +1,160 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Answer, ParseError};
+use crate::generate::Generator;
+use crate::reference::{self, Disagreement, Reference};
+use crate::rng::Rng;
+use crate::stream::{Stream, StreamError, Streaming};
+
+/// A day's puzzle: the input gets parsed once and both parts are computed from it.
+pub trait Solution {
+    type Input: 'static;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// Parts whose answers have to be checked by hand, e.g. because they only work for some inputs.
+    const MANUAL_PARTS: &'static [u8] = &[];
+    /// Parts which aren't solved yet, the runner reports them instead of running them.
+    const UNSOLVED_PARTS: &'static [u8] = &[];
+
+    fn parse(rows: &Vec<String>) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Solves part 1 of an [example](crate::examples), for days whose examples are scaled down from the real puzzle.
+    fn example_part1(input: &Self::Input) -> Self::Part1 {
+        Self::part1(input)
+    }
+
+    /// Solves part 2 of an [example](crate::examples), see [Solution::example_part1].
+    fn example_part2(input: &Self::Input) -> Self::Part2 {
+        Self::part2(input)
+    }
+}
+
+/// A day's [Solution] with its input type erased and its answers converted,
+/// so that all days can be kept in one table.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&Vec<String>) -> Result<Box<dyn Any>, ParseError>,
+    pub part1: fn(&dyn Any) -> Answer,
+    pub part2: fn(&dyn Any) -> Answer,
+    /// see [Solution::MANUAL_PARTS]
+    pub manual: &'static [u8],
+    /// see [Solution::UNSOLVED_PARTS]
+    pub unsolved: &'static [u8],
+    /// The parts reading the input themselves, for the days which can.
+    pub streamed: Option<Streamed>,
+    /// What makes up inputs, for the days which can.
+    pub generator: Option<InputGenerator>,
+    /// What compares parts with their naive counterparts, for the days which have them.
+    pub reference: Option<Differential>,
+}
+
+/// The input generator of a [Generator] solution.
+#[derive(Clone, Copy)]
+pub struct InputGenerator {
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// see [Generator::SIZE]
+    pub size: usize,
+}
+
+/// The comparison of a [Reference] solution with its naive parts.
+#[derive(Clone, Copy)]
+pub struct Differential {
+    /// see [reference::compare]
+    pub compare: fn(u64, usize, u8) -> Option<Disagreement>,
+    /// see [Reference::PARTS]
+    pub parts: &'static [u8],
+    /// see [Reference::REFERENCE_SIZE]
+    pub size: usize,
+}
+
+/// The parts and the input generator of a [Streaming] solution.
+#[derive(Clone, Copy)]
+pub struct Streamed {
+    pub part1: fn(&mut Stream) -> Result<Answer, StreamError>,
+    pub part2: fn(&mut Stream) -> Result<Answer, StreamError>,
+    pub generate: fn(&mut Rng, &mut String),
+    pub generate_end: fn(&mut String),
+}
+
+impl Streamed {
+    pub fn part(&self, part: u8) -> fn(&mut Stream) -> Result<Answer, StreamError> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("There is no part {part}"),
+        }
+    }
+}
+
+/// Turns a panic into an error with its message.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        payload.downcast_ref::<&str>().map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown reason".to_string())
+    })
+}
+
+fn input<S: Solution>(input: &dyn Any) -> &S::Input {
+    input.downcast_ref().expect("Input parsed by another puzzle")
+}
+
+impl Puzzle {
+    pub fn new<S: Solution>(year: u16, day: u8) -> Self {
+        Puzzle {
+            year,
+            day,
+            parse: |rows| S::parse(rows).map(|input| Box::new(input) as Box<dyn Any>),
+            part1: |parsed| S::part1(input::<S>(parsed)).into(),
+            part2: |parsed| S::part2(input::<S>(parsed)).into(),
+            manual: S::MANUAL_PARTS,
+            unsolved: S::UNSOLVED_PARTS,
+            streamed: None,
+            generator: None,
+            reference: None,
+        }
+    }
+
+    /// A day which can make up its inputs.
+    pub fn generating<G: Generator>(year: u16, day: u8) -> Self {
+        Puzzle {
+            generator: Some(InputGenerator { generate: G::generate_input, size: G::SIZE }),
+            ..Puzzle::new::<G>(year, day)
+        }
+    }
+
+    /// A day whose parts can be compared with naive ones.
+    pub fn comparing<R: Reference>(year: u16, day: u8) -> Self {
+        Puzzle {
+            reference: Some(Differential { compare: reference::compare::<R>, parts: R::PARTS, size: R::REFERENCE_SIZE }),
+            ..Puzzle::generating::<R>(year, day)
+        }
+    }
+
+    /// A day whose parts can also stream the input.
+    pub fn streaming<S: Streaming + Generator>(year: u16, day: u8) -> Self {
+        Puzzle {
+            streamed: Some(Streamed {
+                part1: |input| S::stream_part1(input).map(Into::into),
+                part2: |input| S::stream_part2(input).map(Into::into),
+                generate: <S as Streaming>::generate,
+                generate_end: S::generate_end,
+            }),
+            ..Puzzle::generating::<S>(year, day)
+        }
+    }
+
+    pub fn part(&self, part: u8) -> fn(&dyn Any) -> Answer {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("There is no part {part}"),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Solving a puzzle while reading its input, for inputs far too large to keep in memory.
+//!
+//! A [Streaming] solution reads the rows (or the bytes of a row) one at a time from a [Stream]
+//! instead of getting all of them parsed at once, and can make up inputs of any size to feed it.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+
+use crate::input::Source;
+use crate::parse::{ParseError, Token};
+use crate::rng::Rng;
+use crate::solution::Solution;
+
+/// Rows longer than this aren't shown with the errors about them.
+const MAX_SHOWN_ROW: usize = 200;
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// With the offending row, unless it is too long to keep.
+    Parse(ParseError, Option<String>),
+    /// The puzzle doesn't have the part, e.g. the last day of a year.
+    NoPart(u8),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{err}"),
+            StreamError::Parse(err, Some(row)) => write!(f, "{}", err.diagnostic_of_row(row)),
+            StreamError::Parse(err, None) => write!(f, "{err}"),
+            StreamError::NoPart(part) => write!(f, "there is no part {part}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> StreamError {
+        StreamError::Io(err)
+    }
+}
+
+/// The input read one row at a time, numbered like the rows of [lines](crate::lines).
+pub struct Stream<'r> {
+    reader: Box<dyn BufRead + 'r>,
+    row: String,
+    line: usize,
+}
+
+impl<'r> Stream<'r> {
+    pub fn new(reader: impl BufRead + 'r) -> Stream<'r> {
+        Stream { reader: Box::new(reader), row: String::new(), line: 0 }
+    }
+
+    pub fn open(source: &Source) -> io::Result<Stream<'static>> {
+        Ok(match source {
+            Source::File(path) => Stream::new(BufReader::new(File::open(path)?)),
+            Source::Stdin => Stream::new(io::stdin().lock()),
+            Source::Text(text) => Stream::new(Cursor::new(text.clone().into_bytes())),
+        })
+    }
+
+    /// The next row without its line ending, none at the end of the input.
+    pub fn next_row(&mut self) -> Result<Option<Token<'_>>, StreamError> {
+        self.row.clear();
+        if self.reader.read_line(&mut self.row)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        let text = self.row.strip_suffix('\n').map_or(&*self.row, |row| row.strip_suffix('\r').unwrap_or(row));
+        Ok(Some(Token { line: self.line, column: 1, text }))
+    }
+
+    /// Calls `f` with each of the remaining rows, a parse error comes with the row it is about.
+    pub fn try_for_each_row(&mut self, mut f: impl FnMut(Token) -> Result<(), ParseError>) -> Result<(), StreamError> {
+        self.try_fold_rows((), |_, row| f(row))
+    }
+
+    /// Folds the remaining rows into a single value, like [Iterator::try_fold].
+    pub fn try_fold_rows<B>(&mut self, init: B, mut f: impl FnMut(B, Token) -> Result<B, ParseError>) -> Result<B, StreamError> {
+        let mut acc = init;
+        while let Some(row) = self.next_row()? {
+            acc = match f(acc, row) {
+                Ok(acc) => acc,
+                Err(err) => return Err(self.error(err)),
+            };
+        }
+        Ok(acc)
+    }
+
+    /// The bytes of the next row one at a time, for rows too long to keep.
+    pub fn row_bytes(&mut self) -> RowBytes<'_, 'r> {
+        self.row.clear();
+        self.line += 1;
+        RowBytes { stream: self, chunk: vec!(), pos: 0, column: 0, ends: false }
+    }
+
+    /// Adds the current row to the error if it is about it.
+    pub fn error(&self, err: ParseError) -> StreamError {
+        let row = (err.line == self.line && self.row.len() <= MAX_SHOWN_ROW)
+            .then(|| self.row.trim_end_matches(['\n', '\r']).to_string());
+        StreamError::Parse(err, row)
+    }
+}
+
+/// See [Stream::row_bytes].
+pub struct RowBytes<'s, 'r> {
+    stream: &'s mut Stream<'r>,
+    // the part of the row taken from the reader at once, rather than going through it for every byte
+    chunk: Vec<u8>,
+    pos: usize,
+    column: usize,
+    ends: bool,
+}
+
+impl RowBytes<'_, '_> {
+    /// An error about the last byte.
+    pub fn error(&self, byte: u8, reason: impl Into<String>) -> StreamError {
+        let token = Token { line: self.stream.line, column: self.column, text: &String::from(byte as char) }.error(reason);
+        StreamError::Parse(token, None)
+    }
+
+    /// Takes as much of the row as the reader has buffered.
+    fn fill_chunk(&mut self) -> io::Result<()> {
+        self.chunk.clear();
+        self.pos = 0;
+        let reader = &mut self.stream.reader;
+        let buffer = reader.fill_buf()?;
+        let consumed = match buffer.iter().position(|&byte| byte == b'\n') {
+            Some(idx) => {
+                self.chunk.extend_from_slice(buffer[..idx].strip_suffix(b"\r").unwrap_or(&buffer[..idx]));
+                self.ends = true;
+                idx + 1
+            }
+            None if buffer.is_empty() => {
+                self.ends = true;
+                0
+            }
+            // a \r at the end may be the start of a line ending, which is left to the next chunk
+            None if buffer.len() > 1 && buffer.ends_with(b"\r") => {
+                self.chunk.extend_from_slice(&buffer[..buffer.len() - 1]);
+                buffer.len() - 1
+            }
+            None if buffer == b"\r" => {
+                reader.consume(1);
+                if reader.fill_buf()?.first() == Some(&b'\n') {
+                    self.ends = true;
+                    1
+                } else {
+                    self.chunk.push(b'\r');
+                    0
+                }
+            }
+            None => {
+                self.chunk.extend_from_slice(buffer);
+                buffer.len()
+            }
+        };
+        reader.consume(consumed);
+        Ok(())
+    }
+}
+
+impl Iterator for RowBytes<'_, '_> {
+    type Item = Result<u8, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&byte) = self.chunk.get(self.pos) {
+                self.pos += 1;
+                self.column += 1;
+                return Some(Ok(byte));
+            }
+            if self.ends {
+                return None;
+            }
+            if let Err(err) = self.fill_chunk() {
+                self.ends = true;
+                return Some(Err(err.into()));
+            }
+        }
+    }
+}
+
+/// A [Solution] whose parts can also read the input while solving it.
+pub trait Streaming: Solution {
+    fn stream_part1(input: &mut Stream) -> Result<Self::Part1, StreamError>;
+    fn stream_part2(input: &mut Stream) -> Result<Self::Part2, StreamError>;
+
+    /// Appends the next piece of a made up input, any number of pieces make a valid input.
+    fn generate(rng: &mut Rng, input: &mut String);
+
+    /// Appends the end of a made up input, e.g. what has to be found eventually.
+    fn generate_end(_input: &mut String) {}
+}
+
+/// A made up input of at least `size` bytes, generated while it is read so that it takes no memory.
+pub struct Generated {
+    rng: Rng,
+    generate: fn(&mut Rng, &mut String),
+    generate_end: fn(&mut String),
+    remaining: u64,
+    buffer: String,
+    pos: usize,
+    ended: bool,
+}
+
+/// The pieces are generated in batches of about this many bytes.
+const BATCH: usize = 1 << 16;
+
+impl Generated {
+    pub fn new<S: Streaming>(seed: u64, size: u64) -> Generated {
+        Generated::with(seed, size, S::generate, S::generate_end)
+    }
+
+    pub fn with(seed: u64, size: u64, generate: fn(&mut Rng, &mut String), generate_end: fn(&mut String)) -> Generated {
+        Generated { rng: Rng::new(seed), generate, generate_end, remaining: size, buffer: String::new(), pos: 0, ended: false }
+    }
+}
+
+impl Read for Generated {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl BufRead for Generated {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.buffer.len() && !self.ended {
+            self.buffer.clear();
+            self.pos = 0;
+            while self.remaining > 0 && self.buffer.len() < BATCH {
+                let len = self.buffer.len();
+                (self.generate)(&mut self.rng, &mut self.buffer);
+                self.remaining = self.remaining.saturating_sub((self.buffer.len() - len) as u64);
+            }
+            if self.remaining == 0 {
+                (self.generate_end)(&mut self.buffer);
+                self.ended = true;
+            }
+        }
+        Ok(&self.buffer.as_bytes()[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buffer.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(text: &str) -> Stream<'static> {
+        Stream::new(Cursor::new(text.to_string().into_bytes()))
+    }
+
+    #[test]
+    fn reads_rows_and_bytes() {
+        let mut input = stream("12\r\nab\n\nxyz");
+        assert_eq!(input.next_row().unwrap().map(|row| row.text.to_string()), Some("12".to_string()));
+        assert_eq!(input.row_bytes().collect::<Result<Vec<_>, _>>().unwrap(), b"ab");
+        let mut rows = vec!();
+        input.try_for_each_row(|row| {
+            rows.push((row.line, row.text.to_string()));
+            Ok(())
+        }).unwrap();
+        assert_eq!(rows, [(3, "".to_string()), (4, "xyz".to_string())]);
+        assert!(input.next_row().unwrap().is_none());
+
+        // rows and line endings split up by the buffer of the reader
+        let mut input = Stream::new(BufReader::with_capacity(3, Cursor::new(b"abcd\r\nef\r\r\ngh".to_vec())));
+        let rows = (0..4).map(|_| input.row_bytes().collect::<Result<Vec<_>, _>>().unwrap()).collect::<Vec<_>>();
+        assert_eq!(rows, [b"abcd".to_vec(), b"ef\r".to_vec(), b"gh".to_vec(), vec!()]);
+    }
+
+    #[test]
+    fn errors_point_to_the_input() {
+        let mut input = stream("1\nx\n");
+        let err = input.try_for_each_row(|row| row.parse::<u8>().map(|_| ())).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected u8, found \"x\"\n  x\n  ^");
+
+        let mut input = stream("ab3");
+        let mut bytes = input.row_bytes();
+        let err = bytes.find_map(|byte| byte.ok().filter(u8::is_ascii_digit)).map(|byte| bytes.error(byte, "expected a letter"));
+        assert_eq!(err.unwrap().to_string(), "line 1, column 3: expected a letter, found \"3\"");
+    }
+
+    #[test]
+    fn generates_inputs_of_the_given_size() {
+        fn generate(rng: &mut Rng, input: &mut String) {
+            input.push_str(&format!("{}\n", rng.range(100..=999)));
+        }
+        let read = |seed, size| {
+            let mut text = String::new();
+            Generated::with(seed, size, generate, |input| input.push_str("end\n")).read_to_string(&mut text).unwrap();
+            text
+        };
+        let text = read(3, 1 << 20);
+        assert_eq!(text.len(), (1 << 20) + 4);
+        assert!(text.ends_with("\nend\n"));
+        assert_eq!(text, read(3, 1 << 20));
+        assert_ne!(text, read(4, 1 << 20));
+        assert_eq!(read(3, 0), "end\n");
+    }
+}
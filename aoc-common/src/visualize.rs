@@ -0,0 +1,143 @@
+//! Frames of the simulations, shown by the runner with `--visualize`.
+//!
+//! The days hand each step of a simulation to [show], which neither renders nor writes anything
+//! unless the runner started an [Output] before, so the solutions run as fast as without it.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    pub const WHITE: Color = Color::new(255, 255, 255);
+    pub const GREY: Color = Color::new(128, 128, 128);
+    pub const RED: Color = Color::new(220, 50, 47);
+    pub const GREEN: Color = Color::new(133, 153, 0);
+    pub const BLUE: Color = Color::new(38, 139, 210);
+    pub const CYAN: Color = Color::new(42, 161, 152);
+    pub const YELLOW: Color = Color::new(181, 137, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+}
+
+/// A character for the terminal, the images only show its color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Pixel {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Pixel {
+    pub const EMPTY: Pixel = Pixel::new(' ', Color::BLACK);
+
+    pub const fn new(glyph: char, color: Color) -> Pixel {
+        Pixel { glyph, color }
+    }
+}
+
+pub type Frame = Grid<Pixel>;
+
+/// The state of a simulation which can be drawn as a frame.
+pub trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// Where the frames go, set up by the runner.
+pub trait Output: Send {
+    fn write(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+// the output, else the error it failed with
+static OUTPUT: Mutex<Option<Result<Box<dyn Output>, io::Error>>> = Mutex::new(None);
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Result<Box<dyn Output>, io::Error>>> {
+    OUTPUT.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Sends the frames of all following calls to [show] to the output.
+pub fn start(output: Box<dyn Output>) {
+    *lock() = Some(Ok(output));
+    ACTIVE.store(true, Ordering::Relaxed);
+}
+
+/// Stops showing frames, with the error if writing one of them failed.
+pub fn finish() -> io::Result<()> {
+    ACTIVE.store(false, Ordering::Relaxed);
+    match lock().take() {
+        Some(Err(err)) => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Whether the frames are shown, for the simulations which need extra work to show their steps.
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+/// Renders the next frame and writes it if the frames are shown, after an error the remaining ones
+/// are skipped.
+pub fn show(state: &impl Render) {
+    if !is_active() {
+        return;
+    }
+    let mut output = lock();
+    if let Some(Ok(out)) = output.as_mut() {
+        if let Err(err) = out.write(&state.render()) {
+            ACTIVE.store(false, Ordering::Relaxed);
+            *output = Some(Err(err));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    struct Counter(u8);
+
+    impl Render for Counter {
+        fn render(&self) -> Frame {
+            Grid::new(1, 1, Pixel::new((b'0' + self.0) as char, Color::WHITE))
+        }
+    }
+
+    struct Collect(Arc<Mutex<Vec<char>>>, usize);
+
+    impl Output for Collect {
+        fn write(&mut self, frame: &Frame) -> io::Result<()> {
+            let mut frames = self.0.lock().unwrap();
+            if frames.len() == self.1 {
+                return Err(io::Error::other("disk full"));
+            }
+            frames.push(frame[(0, 0)].glyph);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn shows_frames_while_started() {
+        let frames = Arc::new(Mutex::new(vec!()));
+        show(&Counter(0));
+        start(Box::new(Collect(frames.clone(), 2)));
+        (1..=4).for_each(|n| show(&Counter(n)));
+        assert_eq!(finish().unwrap_err().to_string(), "disk full");
+        show(&Counter(5));
+        assert_eq!(*frames.lock().unwrap(), ['1', '2']);
+        assert!(finish().is_ok());
+    }
+}
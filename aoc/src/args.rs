@@ -0,0 +1,625 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use aoc_common::Source;
+
+pub const USAGE: &str = "\
+Usage: aoc run [--year YEAR] --day DAY [--part PART] [--input PATH | --input-text TEXT]...
+               [--verbose] [--visualize OUTPUT] [--format FORMAT] [--threads THREADS] [--stream]
+       aoc run --all [--year YEAR] [--verbose] [--format FORMAT] [--threads THREADS] [--stream]
+       aoc bench [--year YEAR] (--day DAY | --all) [--part PART] [--input PATH | --input-text TEXT]
+                 [--runs RUNS] [--format FORMAT] [--threads THREADS]
+       aoc fetch [--year YEAR] (--day DAY | --all) [--force]
+       aoc submit [--year YEAR] --day DAY --part PART
+                  [--answer ANSWER | --input PATH | --input-text TEXT]
+       aoc stress [--year YEAR] (--day DAY | --all) [--part PART] [--size SIZE] [--seed SEED]
+       aoc generate [--year YEAR] --day DAY [--size SIZE] [--seed SEED]
+       aoc compare [--year YEAR] (--day DAY | --all) [--part PART] [--size SIZE] [--seeds SEEDS]
+       aoc new [--year YEAR] --day DAY
+       aoc session TOKEN
+
+Options:
+  --year YEAR      year of the puzzle, defaults to the latest one
+  --day DAY        day of the puzzle (1-25)
+  --part PART      only run the given part (1 or 2)
+  --input PATH     puzzle input, defaults to YEAR/dayDD/input or the fetched one, - reads stdin;
+                   run takes several of them and solves each in turn
+  --input-text TEXT
+                   the puzzle input itself instead of a file
+  --all            run all days (of the given year)
+  --runs RUNS      how often each step is timed, defaults to 5
+  --format FORMAT  output of the answers (text or json) or the timings (text, json or csv),
+                   defaults to text
+  --force          fetch the input again even if it is cached already
+  --answer ANSWER  answer to submit instead of solving the part
+  --verbose        show how well the caches of each part did
+  --threads THREADS
+                   how many threads the solutions spread their work over, defaults to one per core
+  --visualize OUTPUT
+                   show the steps of the simulations: ansi animates them in the terminal,
+                   anything else is a directory to write them to as PPM images
+  --stream         let each part read the input while solving it instead of parsing it first,
+                   for the days which can
+  --size SIZE      how much input to make up: for stress the bytes, with an optional K, M or G
+                   suffix, defaults to 1G; for generate and compare whatever the day counts in,
+                   e.g. lines, monkeys or the side of a map, defaults to the size of a real input
+                   or, for compare, one the naive parts still solve quickly
+  --seed SEED      what the made up input is generated from, defaults to 1
+  --seeds SEEDS    how many made up inputs to compare on, from seed 1 on, defaults to 100
+
+`stress` feeds the days which can stream their input with a made up one of the given size, without
+keeping it in memory, and reports how fast it was read and solved.
+
+`generate` writes a made up input of the day to stdout, which can be shared freely unlike the real
+ones, e.g. `aoc generate --day 16 --seed 3 | aoc bench --day 16 --input -`.
+
+`compare` checks the parts which have naive counterparts, slow but simple, against them on made up
+inputs and shows the first one they disagree on, cut down to as little as still disagrees.
+
+`new` creates the crate of a day from a template with an empty example and registers it in the
+workspace and the runner, it has to be run from the root of the repository.
+
+Submitted answers are kept in a history next to the cached input, answers known to be
+wrong or beyond an answer which was too high or too low aren't submitted again.
+
+The inputs are fetched with the session cookie of the website, stored by `aoc session`
+or given as AOC_SESSION. AOC_BASE_URL, AOC_SESSION_FILE and AOC_CACHE_DIR override
+where they are fetched from, where the cookie is stored and where the inputs are cached.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Selection, RunOptions),
+    Bench(Selection, BenchOptions),
+    Fetch(Selection, FetchOptions),
+    Submit(Selection, SubmitOptions),
+    Stress(Selection, StressOptions),
+    Generate(Selection, GenerateOptions),
+    Compare(Selection, CompareOptions),
+    New(Selection),
+    Session(String),
+    Help,
+}
+
+/// Which puzzles and parts to run.
+#[derive(Debug, Default, PartialEq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    /// The inputs given on the command line, the default one if there are none.
+    pub inputs: Vec<Source>,
+    pub all: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Where the frames of `--visualize` go.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Visualization {
+    Ansi,
+    Images(PathBuf),
+}
+
+impl FromStr for Visualization {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(()),
+            "ansi" => Ok(Visualization::Ansi),
+            dir => Ok(Visualization::Images(PathBuf::from(dir))),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct RunOptions {
+    pub verbose: bool,
+    pub visualize: Option<Visualization>,
+    pub format: Format,
+    pub threads: Option<usize>,
+    pub stream: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub format: Format,
+    pub threads: Option<usize>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { runs: 5, format: Format::Text, threads: None }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct FetchOptions {
+    pub force: bool,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SubmitOptions {
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StressOptions {
+    /// How many bytes of input to make up, the end of the input comes on top.
+    pub size: u64,
+    pub seed: u64,
+}
+
+impl Default for StressOptions {
+    fn default() -> Self {
+        StressOptions { size: 1 << 30, seed: 1 }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateOptions {
+    /// The size in whatever the day counts in, the one of a real input if not given.
+    pub size: Option<usize>,
+    pub seed: u64,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions { size: None, seed: 1 }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CompareOptions {
+    /// The size in whatever the day counts in, one the naive parts solve quickly if not given.
+    pub size: Option<usize>,
+    /// How many inputs to make up, from seed 1 on.
+    pub seeds: u64,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        CompareOptions { size: None, seeds: 100 }
+    }
+}
+
+/// A number of bytes like `512`, `64K`, `100M` or `2G`.
+struct Size(u64);
+
+impl FromStr for Size {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, shift) = match s.as_bytes().last() {
+            Some(b'K') => (&s[..s.len() - 1], 10),
+            Some(b'M') => (&s[..s.len() - 1], 20),
+            Some(b'G') => (&s[..s.len() - 1], 30),
+            _ => (s, 0),
+        };
+        let number = number.parse::<u64>().map_err(|_| ())?;
+        number.checked_mul(1 << shift).map(Size).ok_or(())
+    }
+}
+
+fn value<T: FromStr>(flag: &str, args: &mut impl Iterator<Item=String>) -> Result<T, String> {
+    let value = args.next().ok_or(format!("Missing value for {flag}"))?;
+    value.parse().map_err(|_| format!("Invalid value for {flag}: {value}"))
+}
+
+/// Applies a flag selecting the puzzles, returns false if it isn't one of those.
+fn selection_flag(selection: &mut Selection, arg: &str, args: &mut impl Iterator<Item=String>) -> Result<bool, String> {
+    match arg {
+        "--year" => selection.year = Some(value(arg, args)?),
+        "--day" => selection.day = Some(value(arg, args)?),
+        "--part" => selection.part = Some(value(arg, args)?),
+        "--input" => selection.inputs.push(value(arg, args)?),
+        "--input-text" => selection.inputs.push(Source::Text(value(arg, args)?)),
+        "--all" => selection.all = true,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn validate_selection(selection: Selection) -> Result<Selection, String> {
+    if selection.day.is_some_and(|day| !(1..=25).contains(&day)) {
+        return Err("The day has to be within 1 and 25".to_string());
+    }
+    if selection.part.is_some_and(|part| part != 1 && part != 2) {
+        return Err("The part has to be 1 or 2".to_string());
+    }
+    if selection.all {
+        if selection.day.is_some() || !selection.inputs.is_empty() {
+            return Err("--all can't be combined with --day or --input".to_string());
+        }
+    } else if selection.day.is_none() {
+        return Err("Either --day or --all is needed".to_string());
+    }
+    if selection.inputs.iter().filter(|&input| *input == Source::Stdin).count() > 1 {
+        return Err("stdin can only be read once".to_string());
+    }
+
+    Ok(selection)
+}
+
+fn parse_run(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = RunOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--verbose" => options.verbose = true,
+            "--visualize" => options.visualize = Some(value(&arg, args)?),
+            "--format" => options.format = value(&arg, args)?,
+            "--threads" => options.threads = Some(value(&arg, args)?),
+            "--stream" => options.stream = true,
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    if options.threads == Some(0) {
+        return Err("At least one thread is needed".to_string());
+    }
+    // each part reads the streamed input on its own
+    if options.stream && selection.part.is_none() && selection.inputs.contains(&Source::Stdin) {
+        return Err("A part is needed to stream stdin, it can only be read once".to_string());
+    }
+    if options.format == Format::Csv {
+        return Err("The answers can't be written as csv".to_string());
+    }
+    // the records are written to stdout as well
+    if options.format == Format::Json && options.visualize == Some(Visualization::Ansi) {
+        return Err("--visualize ansi can't be combined with --format json".to_string());
+    }
+    // the frames of each input would overwrite the ones before
+    if matches!(options.visualize, Some(Visualization::Images(_))) && selection.inputs.len() > 1 {
+        return Err("The frames of several inputs can't be written as images".to_string());
+    }
+
+    Ok(Command::Run(validate_selection(selection)?, options))
+}
+
+fn parse_bench(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = BenchOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => options.runs = value(&arg, args)?,
+            "--format" => options.format = value(&arg, args)?,
+            "--threads" => options.threads = Some(value(&arg, args)?),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    if options.runs == 0 {
+        return Err("At least one run is needed".to_string());
+    }
+    if options.threads == Some(0) {
+        return Err("At least one thread is needed".to_string());
+    }
+    if selection.inputs.len() > 1 {
+        return Err("Only a single input can be benchmarked".to_string());
+    }
+
+    Ok(Command::Bench(validate_selection(selection)?, options))
+}
+
+fn parse_fetch(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = FetchOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--force" => options.force = true,
+            "--part" | "--input" | "--input-text" => return Err(format!("{arg} can't be used to fetch inputs")),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    Ok(Command::Fetch(validate_selection(selection)?, options))
+}
+
+fn parse_submit(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = SubmitOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answer" => options.answer = Some(value(&arg, args)?),
+            "--all" => return Err("Only a single answer can be submitted".to_string()),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    if selection.part.is_none() {
+        return Err("The part to submit is needed".to_string());
+    }
+    if options.answer.is_some() && !selection.inputs.is_empty() {
+        return Err("--answer can't be combined with --input".to_string());
+    }
+    if selection.inputs.len() > 1 {
+        return Err("Only a single input can be submitted".to_string());
+    }
+
+    Ok(Command::Submit(validate_selection(selection)?, options))
+}
+
+fn parse_stress(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = StressOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => options.size = value::<Size>(&arg, args)?.0,
+            "--seed" => options.seed = value(&arg, args)?,
+            "--input" | "--input-text" => return Err(format!("{arg} can't be used with made up inputs")),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    Ok(Command::Stress(validate_selection(selection)?, options))
+}
+
+fn parse_generate(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = GenerateOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => options.size = Some(value(&arg, args)?),
+            "--seed" => options.seed = value(&arg, args)?,
+            "--all" => return Err("Only a single input can be made up".to_string()),
+            "--part" | "--input" | "--input-text" => return Err(format!("{arg} can't be used to make up an input")),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    Ok(Command::Generate(validate_selection(selection)?, options))
+}
+
+fn parse_compare(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+    let mut options = CompareOptions::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => options.size = Some(value(&arg, args)?),
+            "--seeds" => options.seeds = value(&arg, args)?,
+            "--input" | "--input-text" => return Err(format!("{arg} can't be used with made up inputs")),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    if options.seeds == 0 {
+        return Err("At least one seed is needed".to_string());
+    }
+
+    Ok(Command::Compare(validate_selection(selection)?, options))
+}
+
+fn parse_new(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    let mut selection = Selection::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" | "--part" | "--input" | "--input-text" => return Err(format!("{arg} can't be used to create a day")),
+            _ => if !selection_flag(&mut selection, &arg, args)? {
+                return Err(format!("Unknown argument {arg}"));
+            },
+        }
+    }
+
+    Ok(Command::New(validate_selection(selection)?))
+}
+
+fn parse_session(args: &mut impl Iterator<Item=String>) -> Result<Command, String> {
+    match (args.next(), args.next()) {
+        (Some(token), None) if !token.starts_with("--") => Ok(Command::Session(token)),
+        _ => Err("The session cookie is needed and nothing else".to_string()),
+    }
+}
+
+/// Parses the arguments without the name of the executable.
+pub fn parse(args: impl IntoIterator<Item=String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(&mut args),
+        Some("bench") => parse_bench(&mut args),
+        Some("fetch") => parse_fetch(&mut args),
+        Some("submit") => parse_submit(&mut args),
+        Some("stress") => parse_stress(&mut args),
+        Some("generate") => parse_generate(&mut args),
+        Some("compare") => parse_compare(&mut args),
+        Some("new") => parse_new(&mut args),
+        Some("session") => parse_session(&mut args),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("Unknown command {other}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_run() {
+        assert_eq!(parse_str("run --year 2022 --day 16 --part 2 --input foo/bar"), Ok(Command::Run(Selection {
+            year: Some(2022),
+            day: Some(16),
+            part: Some(2),
+            inputs: vec!(Source::File(PathBuf::from("foo/bar"))),
+            all: false,
+        }, RunOptions::default())));
+        assert_eq!(parse_str("run --day 1 --input - --input-text 1000 --input a"), Ok(Command::Run(
+            Selection {
+                day: Some(1),
+                inputs: vec!(Source::Stdin, Source::Text("1000".to_string()), Source::File(PathBuf::from("a"))),
+                ..Selection::default()
+            },
+            RunOptions::default())));
+        assert_eq!(parse_str("run --all --verbose"), Ok(Command::Run(
+            Selection { all: true, ..Selection::default() },
+            RunOptions { verbose: true, ..RunOptions::default() })));
+        assert_eq!(parse_str("run --all --format json --threads 1"), Ok(Command::Run(
+            Selection { all: true, ..Selection::default() },
+            RunOptions { format: Format::Json, threads: Some(1), ..RunOptions::default() })));
+        assert_eq!(parse_str("run --day 14 --visualize ansi"), Ok(Command::Run(
+            Selection { day: Some(14), ..Selection::default() },
+            RunOptions { visualize: Some(Visualization::Ansi), ..RunOptions::default() })));
+        assert_eq!(parse_str("run --day 14 --visualize frames/day14"), Ok(Command::Run(
+            Selection { day: Some(14), ..Selection::default() },
+            RunOptions { visualize: Some(Visualization::Images(PathBuf::from("frames/day14"))), ..RunOptions::default() })));
+        assert_eq!(parse_str("run --day 1 --input - --part 2 --stream"), Ok(Command::Run(
+            Selection { day: Some(1), part: Some(2), inputs: vec!(Source::Stdin), ..Selection::default() },
+            RunOptions { stream: true, ..RunOptions::default() })));
+        assert_eq!(parse_str(""), Ok(Command::Help));
+    }
+
+    #[test]
+    fn parses_bench() {
+        assert_eq!(parse_str("bench --all --runs 10 --format csv --threads 4"), Ok(Command::Bench(
+            Selection { all: true, ..Selection::default() },
+            BenchOptions { runs: 10, format: Format::Csv, threads: Some(4) })));
+        assert_eq!(parse_str("bench --day 15"), Ok(Command::Bench(
+            Selection { day: Some(15), ..Selection::default() },
+            BenchOptions::default())));
+        assert!(parse_str("bench --day 15 --runs 0").is_err());
+        assert!(parse_str("bench --day 15 --threads 0").is_err());
+        assert!(parse_str("bench --day 15 --format xml").is_err());
+        assert!(parse_str("run --day 15 --runs 3").is_err());
+        assert!(parse_str("bench --day 15 --input a --input b").is_err());
+    }
+
+    #[test]
+    fn parses_fetch_and_session() {
+        assert_eq!(parse_str("fetch --day 3 --force"), Ok(Command::Fetch(
+            Selection { day: Some(3), ..Selection::default() },
+            FetchOptions { force: true })));
+        assert!(parse_str("fetch --day 3 --input foo").is_err());
+        assert!(parse_str("fetch --day 3 --input-text 1").is_err());
+        assert_eq!(parse_str("session 53616c74"), Ok(Command::Session("53616c74".to_string())));
+        assert!(parse_str("session").is_err());
+        assert!(parse_str("session a b").is_err());
+    }
+
+    #[test]
+    fn parses_new() {
+        assert_eq!(parse_str("new --year 2023 --day 1"), Ok(Command::New(
+            Selection { year: Some(2023), day: Some(1), ..Selection::default() })));
+        assert!(parse_str("new --year 2023").is_err());
+        assert!(parse_str("new --all").is_err());
+        assert!(parse_str("new --day 1 --part 2").is_err());
+    }
+
+    #[test]
+    fn parses_stress() {
+        assert_eq!(parse_str("stress --day 6 --part 2 --size 64M --seed 7"), Ok(Command::Stress(
+            Selection { day: Some(6), part: Some(2), ..Selection::default() },
+            StressOptions { size: 64 << 20, seed: 7 })));
+        assert_eq!(parse_str("stress --all --size 1000"), Ok(Command::Stress(
+            Selection { all: true, ..Selection::default() },
+            StressOptions { size: 1000, ..StressOptions::default() })));
+        assert_eq!(parse_str("stress --day 1 --size 2G"), Ok(Command::Stress(
+            Selection { day: Some(1), ..Selection::default() },
+            StressOptions { size: 2 << 30, ..StressOptions::default() })));
+        assert!(parse_str("stress --day 1 --size 2T").is_err());
+        assert!(parse_str("stress --day 1 --size G").is_err());
+        assert!(parse_str("stress --day 1 --size 99999999999999G").is_err());
+        assert!(parse_str("stress --day 1 --input foo").is_err());
+    }
+
+    #[test]
+    fn parses_compare() {
+        assert_eq!(parse_str("compare --day 17 --part 2 --size 12 --seeds 1000"), Ok(Command::Compare(
+            Selection { day: Some(17), part: Some(2), ..Selection::default() },
+            CompareOptions { size: Some(12), seeds: 1000 })));
+        assert_eq!(parse_str("compare --all"), Ok(Command::Compare(
+            Selection { all: true, ..Selection::default() },
+            CompareOptions::default())));
+        assert!(parse_str("compare --day 17 --seeds 0").is_err());
+        assert!(parse_str("compare --day 17 --input foo").is_err());
+    }
+
+    #[test]
+    fn parses_generate() {
+        assert_eq!(parse_str("generate --day 16 --size 20 --seed 3"), Ok(Command::Generate(
+            Selection { day: Some(16), ..Selection::default() },
+            GenerateOptions { size: Some(20), seed: 3 })));
+        assert_eq!(parse_str("generate --year 2022 --day 1"), Ok(Command::Generate(
+            Selection { year: Some(2022), day: Some(1), ..Selection::default() },
+            GenerateOptions::default())));
+        assert!(parse_str("generate --day 1 --size 2K").is_err());
+        assert!(parse_str("generate --all").is_err());
+        assert!(parse_str("generate --day 1 --part 1").is_err());
+        assert!(parse_str("generate --day 1 --input foo").is_err());
+    }
+
+    #[test]
+    fn parses_submit() {
+        assert_eq!(parse_str("submit --day 3 --part 2 --answer 1234"), Ok(Command::Submit(
+            Selection { day: Some(3), part: Some(2), ..Selection::default() },
+            SubmitOptions { answer: Some("1234".to_string()) })));
+        assert!(parse_str("submit --day 3 --answer 1234").is_err());
+        assert!(parse_str("submit --all --part 1").is_err());
+        assert!(parse_str("submit --day 3 --part 1 --answer 1 --input foo").is_err());
+        assert!(parse_str("submit --day 3 --part 1 --input - --input foo").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse_str("walk --day 1").is_err());
+        assert!(parse_str("run").is_err());
+        assert!(parse_str("run --day").is_err());
+        assert!(parse_str("run --day x").is_err());
+        assert!(parse_str("run --day 26").is_err());
+        assert!(parse_str("run --day 1 --part 3").is_err());
+        assert!(parse_str("run --all --day 1").is_err());
+        assert!(parse_str("run --all --input-text 1").is_err());
+        assert!(parse_str("run --day 1 --input - --input -").is_err());
+        assert!(parse_str("run --day 1 --loud").is_err());
+        assert!(parse_str("run --day 1 --visualize").is_err());
+        assert!(parse_str("run --day 1 --threads 0").is_err());
+        assert!(parse_str("run --day 1 --format csv").is_err());
+        assert!(parse_str("run --day 1 --format json --visualize ansi").is_err());
+        assert!(parse_str("run --day 1 --visualize frames --input a --input b").is_err());
+        assert!(parse_str("run --day 1 --input - --stream").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::parallel;
+use aoc_common::Puzzle;
+
+use crate::args::{BenchOptions, Format, Selection};
+use crate::run::{input_sources, selected_parts, selected_puzzles, solve};
+
+/// The durations of the repeated runs of one step (parsing or a part) of a puzzle.
+#[derive(Debug, PartialEq)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub step: &'static str,
+    pub durations: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn min(&self) -> Duration {
+        self.durations.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.durations.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => sorted[len / 2],
+            len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+        }
+    }
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> T) -> (Vec<Duration>, T) {
+    let mut durations = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        result = Some(f());
+        durations.push(start.elapsed());
+    }
+    (durations, result.expect("At least one run"))
+}
+
+/// Times parsing the input and solving the parts, each of them `runs` times.
+pub fn measure(puzzle: &Puzzle, parts: &[u8], rows: &Vec<String>, runs: usize) -> Result<Vec<Timing>, String> {
+    let timing = |step, durations| Timing { year: puzzle.year, day: puzzle.day, step, durations };
+
+    let (durations, input) = time(runs, || (puzzle.parse)(rows));
+    let input = input.map_err(|err| format!("Unable to parse the input: {}", err.diagnostic(rows)))?;
+    let mut timings = vec!(timing("parse", durations));
+
+    // there's nothing to time about the parts which aren't solved yet
+    for &part in parts.iter().filter(|part| !puzzle.unsolved.contains(part)) {
+        let (durations, answer) = time(runs, || solve(puzzle, part, &*input));
+        answer.map_err(|reason| format!("Part {part} failed: {reason}"))?;
+        timings.push(timing(if part == 1 { "part1" } else { "part2" }, durations));
+    }
+
+    Ok(timings)
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn format_text(timings: &[Timing]) -> String {
+    let mut text = format!("{:<4} {:>3} {:<5} {:>5} {:>12} {:>12}\n", "year", "day", "step", "runs", "min", "median");
+    for t in timings {
+        text += &format!("{:<4} {:>3} {:<5} {:>5} {:>12} {:>12}\n",
+                         t.year, t.day, t.step, t.durations.len(), millis(t.min()), millis(t.median()));
+    }
+    text
+}
+
+pub fn format_json(timings: &[Timing]) -> String {
+    let records = timings.iter()
+        .map(|t| format!("  {{\"year\": {}, \"day\": {}, \"step\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}}}",
+                         t.year, t.day, t.step, t.durations.len(), t.min().as_nanos(), t.median().as_nanos()))
+        .collect::<Vec<_>>();
+    if records.is_empty() { "[]\n".to_string() } else { format!("[\n{}\n]\n", records.join(",\n")) }
+}
+
+pub fn format_csv(timings: &[Timing]) -> String {
+    let mut csv = "year,day,step,runs,min_ns,median_ns\n".to_string();
+    for t in timings {
+        csv += &format!("{},{},{},{},{},{}\n",
+                        t.year, t.day, t.step, t.durations.len(), t.min().as_nanos(), t.median().as_nanos());
+    }
+    csv
+}
+
+/// Prints the timings of the selected puzzles, returns false if anything went wrong.
+///
+/// Problems are reported on stderr so that the timings on stdout stay machine-readable.
+pub fn bench(selection: &Selection, options: &BenchOptions) -> bool {
+    let puzzles = match selected_puzzles(selection) {
+        Ok(puzzles) => puzzles,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    if let Some(threads) = options.threads {
+        parallel::set_threads(threads);
+    }
+    let parts = selected_parts(selection);
+    let mut timings = vec!();
+    let mut success = true;
+
+    for puzzle in &puzzles {
+        // the arguments allow a single input only
+        let source = input_sources(selection, puzzle).remove(0);
+        let rows = match source.read() {
+            Ok(rows) => rows,
+            Err(err) => {
+                eprintln!("{} day {:02} skipped, unable to read {source}: {err}", puzzle.year, puzzle.day);
+                success &= selection.all;
+                continue;
+            }
+        };
+
+        match measure(puzzle, &parts, &rows, options.runs) {
+            Ok(puzzle_timings) => timings.extend(puzzle_timings),
+            Err(err) => {
+                eprintln!("{} day {:02}: {err}", puzzle.year, puzzle.day);
+                success = false;
+            }
+        }
+    }
+
+    print!("{}", match options.format {
+        Format::Text => format_text(&timings),
+        Format::Json => format_json(&timings),
+        Format::Csv => format_csv(&timings),
+    });
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::readlines;
+
+    use crate::registry;
+
+    use super::*;
+
+    fn timing(millis: &[u64]) -> Timing {
+        Timing { year: 2022, day: 1, step: "part1", durations: millis.iter().map(|&ms| Duration::from_millis(ms)).collect() }
+    }
+
+    #[test]
+    fn computes_statistics() {
+        assert_eq!(timing(&[5, 2, 9]).min(), Duration::from_millis(2));
+        assert_eq!(timing(&[5, 2, 9]).median(), Duration::from_millis(5));
+        assert_eq!(timing(&[5, 2, 9, 1]).median(), Duration::from_millis(3) + Duration::from_micros(500));
+    }
+
+    #[test]
+    fn measures_every_step() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let rows = readlines("../2022/day01/examples/test.in").unwrap();
+        let timings = measure(&puzzle, &[1, 2], &rows, 3).unwrap();
+        assert_eq!(timings.iter().map(|t| t.step).collect::<Vec<_>>(), vec!("parse", "part1", "part2"));
+        assert!(timings.iter().all(|t| t.durations.len() == 3));
+
+        let puzzle = registry::find(2022, 25).unwrap();
+        let rows = readlines("../2022/day25/examples/test.in").unwrap();
+        let timings = measure(&puzzle, &[1, 2], &rows, 1).unwrap();
+        assert_eq!(timings.iter().map(|t| t.step).collect::<Vec<_>>(), vec!("parse", "part1"));
+    }
+
+    #[test]
+    fn formats_timings() {
+        let timings = [timing(&[1, 3])];
+        assert_eq!(format_csv(&timings), "year,day,step,runs,min_ns,median_ns\n2022,1,part1,2,1000000,2000000\n");
+        assert_eq!(format_json(&timings), concat!(
+            "[\n",
+            "  {\"year\": 2022, \"day\": 1, \"step\": \"part1\", \"runs\": 2, \"min_ns\": 1000000, \"median_ns\": 2000000}\n",
+            "]\n"));
+        assert!(format_text(&timings).lines().nth(1).unwrap().ends_with("1.000ms      2.000ms"));
+    }
+}
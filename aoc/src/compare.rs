@@ -0,0 +1,92 @@
+use aoc_common::reference::Disagreement;
+use aoc_common::solution::{catch_panic, Differential};
+
+use crate::args::{CompareOptions, Selection};
+use crate::run::{selected_parts, selected_puzzles};
+
+/// The first disagreement of the part with its naive counterpart on the inputs made up from the seeds,
+/// an error if the naive part fails on one of them.
+pub fn compare_part(reference: &Differential, part: u8, options: &CompareOptions) -> Result<Option<Disagreement>, String> {
+    let size = options.size.unwrap_or(reference.size);
+    for seed in 1..=options.seeds {
+        if let Some(disagreement) = catch_panic(|| (reference.compare)(seed, size, part))? {
+            return Ok(Some(disagreement));
+        }
+    }
+    Ok(None)
+}
+
+/// Compares the parts of the selected days with their naive counterparts, returns false if any of
+/// them disagree or anything else went wrong.
+pub fn compare(selection: &Selection, options: &CompareOptions) -> bool {
+    let puzzles = match selected_puzzles(selection) {
+        Ok(puzzles) => puzzles,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    let mut success = true;
+
+    for puzzle in &puzzles {
+        let Some(reference) = puzzle.reference else {
+            // only matters if the day was asked for explicitly
+            if !selection.all {
+                eprintln!("{} day {:02} has no naive parts to compare with", puzzle.year, puzzle.day);
+                success = false;
+            }
+            continue;
+        };
+        println!("{} day {:02}", puzzle.year, puzzle.day);
+
+        for part in selected_parts(selection) {
+            if !reference.parts.contains(&part) {
+                if selection.part.is_some() {
+                    println!("Part {part} has no naive counterpart");
+                    success = false;
+                }
+                continue;
+            }
+            match compare_part(&reference, part, options) {
+                Ok(None) => println!("Part {part}: agrees on {} made up inputs", options.seeds),
+                Ok(Some(disagreement)) => {
+                    println!("{disagreement}");
+                    success = false;
+                }
+                Err(reason) => {
+                    println!("Part {part} failed: {reason}");
+                    success = false;
+                }
+            }
+        }
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Answer;
+
+    use crate::registry;
+
+    use super::*;
+
+    #[test]
+    fn compares_with_naive_parts() {
+        let reference = registry::find(2022, 20).unwrap().reference.unwrap();
+        let options = CompareOptions { size: None, seeds: 3 };
+        assert_eq!(compare_part(&reference, 1, &options), Ok(None));
+
+        // stands in for a part which gets it wrong
+        let wrong = Differential {
+            compare: |seed, _, part| Some(Disagreement {
+                seed, part, input: "1\n".to_string(), answer: Ok(Answer::Integer(2)), expected: Answer::Integer(1)
+            }),
+            ..reference
+        };
+        assert_eq!(compare_part(&wrong, 2, &options).unwrap().map(|disagreement| disagreement.seed), Some(1));
+        let failing = Differential { compare: |_, _, _| panic!("no input"), ..reference };
+        assert_eq!(compare_part(&failing, 2, &options), Err("no input".to_string()));
+    }
+}
@@ -0,0 +1,118 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Where the puzzles are fetched from and where the session cookie and the inputs are kept, outside of the repo.
+///
+/// Each of them can be overridden by an environment variable: `AOC_BASE_URL`, `AOC_SESSION_FILE` and `AOC_CACHE_DIR`,
+/// the session cookie itself can be given as `AOC_SESSION` instead of storing it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub session_file: PathBuf,
+    pub cache_dir: PathBuf,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Config, String> {
+        Config::from_vars(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+    }
+
+    /// Follows the XDG base directories, falling back to `~/.config` and `~/.cache`.
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        let home = || var("HOME").or_else(|| var("USERPROFILE")).map(PathBuf::from)
+            .ok_or("Unable to find the home directory, set HOME".to_string());
+        let config_dir = || var("XDG_CONFIG_HOME").map(PathBuf::from).map_or_else(|| home().map(|h| h.join(".config")), Ok);
+        let cache_dir = || var("XDG_CACHE_HOME").map(PathBuf::from).map_or_else(|| home().map(|h| h.join(".cache")), Ok);
+
+        Ok(Config {
+            base_url: var("AOC_BASE_URL").unwrap_or(BASE_URL.to_string()).trim_end_matches('/').to_string(),
+            session: var("AOC_SESSION"),
+            session_file: var("AOC_SESSION_FILE").map(PathBuf::from).map_or_else(|| config_dir().map(|d| d.join("aoc/session")), Ok)?,
+            cache_dir: var("AOC_CACHE_DIR").map(PathBuf::from).map_or_else(|| cache_dir().map(|d| d.join("aoc")), Ok)?,
+        })
+    }
+
+    /// The session cookie of the environment or the stored one.
+    pub fn session(&self) -> Result<String, String> {
+        if let Some(session) = &self.session {
+            return Ok(session.clone());
+        }
+        match fs::read_to_string(&self.session_file) {
+            Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+            _ => Err(format!("There is no session cookie, store it with `aoc session TOKEN` (in \"{}\") or set AOC_SESSION",
+                             self.session_file.display())),
+        }
+    }
+
+    pub fn save_session(&self, session: &str) -> Result<(), String> {
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = self.session_file.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&self.session_file, format!("{}\n", session.trim()))?;
+            // the cookie grants access to the account, keep it to ourselves
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                fs::set_permissions(&self.session_file, fs::Permissions::from_mode(0o600))?;
+            }
+            Ok(())
+        };
+        write().map_err(|err| format!("Unable to store the session cookie in \"{}\": {err}", self.session_file.display()))
+    }
+
+    pub fn cached_input(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("{year}/day{day:02}/input"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn config_of(vars: &[(&str, &str)]) -> Result<Config, String> {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        Config::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn follows_the_environment() {
+        let config = config_of(&[("HOME", "/home/santa")]).unwrap();
+        assert_eq!(config, Config {
+            base_url: BASE_URL.to_string(),
+            session: None,
+            session_file: PathBuf::from("/home/santa/.config/aoc/session"),
+            cache_dir: PathBuf::from("/home/santa/.cache/aoc"),
+        });
+        assert_eq!(config.cached_input(2022, 1), PathBuf::from("/home/santa/.cache/aoc/2022/day01/input"));
+
+        let config = config_of(&[
+            ("XDG_CONFIG_HOME", "/cfg"),
+            ("AOC_CACHE_DIR", "/inputs"),
+            ("AOC_BASE_URL", "http://localhost:8080/"),
+            ("AOC_SESSION", "cookie"),
+        ]).unwrap();
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.session_file, PathBuf::from("/cfg/aoc/session"));
+        assert_eq!(config.cache_dir, PathBuf::from("/inputs"));
+        assert_eq!(config.session(), Ok("cookie".to_string()));
+
+        assert!(config_of(&[]).is_err());
+    }
+
+    #[test]
+    fn stores_the_session() {
+        let dir = env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        let config = config_of(&[("HOME", dir.to_str().unwrap())]).unwrap();
+        assert!(config.session().is_err());
+        config.save_session("53616c7465645f5f\n").unwrap();
+        assert_eq!(config.session(), Ok("53616c7465645f5f".to_string()));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
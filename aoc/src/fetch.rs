@@ -0,0 +1,146 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ureq::Agent;
+
+use crate::args::{FetchOptions, Selection};
+use crate::config::Config;
+use crate::registry;
+
+/// The website asks automated tools to tell who they are.
+const USER_AGENT: &str = "github.com/heisenbug23/aoc";
+
+pub fn agent() -> Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+/// Turns a failed request into a readable reason.
+pub fn request_error(err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Status(400, _) => "the session cookie is invalid or expired (400)".to_string(),
+        ureq::Error::Status(404, _) => "the puzzle isn't available (yet) (404)".to_string(),
+        ureq::Error::Status(status, response) => format!("{status} {}", response.status_text()),
+        ureq::Error::Transport(transport) => transport.to_string(),
+    }
+}
+
+pub fn download(config: &Config, year: u16, day: u8) -> Result<String, String> {
+    let session = config.session()?;
+    let url = format!("{}/{year}/day/{day}/input", config.base_url);
+
+    agent().get(&url)
+        .set("Cookie", &format!("session={session}"))
+        .call()
+        .map_err(request_error)?
+        .into_string()
+        .map_err(|err| err.to_string())
+}
+
+/// Downloads the input into the cache unless it is there already, returns where it is.
+pub fn fetch_input(config: &Config, year: u16, day: u8, force: bool) -> Result<(PathBuf, bool), String> {
+    let path = config.cached_input(year, day);
+    if path.exists() && !force {
+        return Ok((path, false));
+    }
+
+    let input = download(config, year, day)?;
+    let write = || -> std::io::Result<()> {
+        fs::create_dir_all(path.parent().expect("The input is within the cache"))?;
+        // written next to it first so that a failure can't leave a truncated input behind
+        let partial = path.with_extension("partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)
+    };
+    write().map_err(|err| format!("Unable to write \"{}\": {err}", path.display()))?;
+
+    Ok((path, true))
+}
+
+/// Fetches the inputs of the selected days, returns false if anything went wrong.
+pub fn fetch(selection: &Selection, options: &FetchOptions) -> bool {
+    let config = match Config::from_env() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    let days: Vec<(u16, u8)> = if selection.all {
+        registry::puzzles().iter()
+            .filter(|p| selection.year.is_none_or(|year| year == p.year))
+            .map(|p| (p.year, p.day))
+            .collect()
+    } else {
+        // the input can be fetched before there is a solution for it
+        vec!((selection.year.unwrap_or_else(registry::latest_year), selection.day.expect("Either a day or all days are selected")))
+    };
+    let mut success = true;
+
+    for (year, day) in days {
+        match fetch_input(&config, year, day, options.force) {
+            Ok((path, true)) => println!("{year} day {day:02}: fetched into \"{}\"", path.display()),
+            Ok((path, false)) => println!("{year} day {day:02}: already in \"{}\"", path.display()),
+            Err(err) => {
+                println!("{year} day {day:02}: unable to fetch the input: {err}");
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::stand_in::StandIn;
+
+    use super::*;
+
+    fn config(base_url: &str, name: &str) -> Config {
+        Config {
+            base_url: base_url.to_string(),
+            session: Some("cookie".to_string()),
+            session_file: PathBuf::from("/nonexistent/session"),
+            cache_dir: env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id())),
+        }
+    }
+
+    #[test]
+    fn fetches_into_the_cache() {
+        let stand_in = StandIn::serve(&[(200, "1000\n2000\n"), (200, "3000\n")]);
+        let config = config(&stand_in.base_url, "cache");
+
+        let (path, fetched) = fetch_input(&config, 2022, 1, false).unwrap();
+        assert!(fetched);
+        assert_eq!(path, config.cache_dir.join("2022/day01/input"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        // cached inputs are only fetched again if asked for
+        assert_eq!(fetch_input(&config, 2022, 1, false), Ok((path.clone(), false)));
+        assert_eq!(fetch_input(&config, 2022, 1, true), Ok((path.clone(), true)));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3000\n");
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+
+        let requests = stand_in.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"), "{}", requests[0]);
+        assert!(requests[0].contains("\r\ncookie: session=cookie\r\n") || requests[0].contains("\r\nCookie: session=cookie\r\n"),
+                "{}", requests[0]);
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let stand_in = StandIn::serve(&[(400, "Puzzle inputs differ by user."), (404, "Not Found")]);
+        let config = config(&stand_in.base_url, "errors");
+        assert_eq!(fetch_input(&config, 2022, 2, false), Err("the session cookie is invalid or expired (400)".to_string()));
+        assert_eq!(fetch_input(&config, 2022, 2, false), Err("the puzzle isn't available (yet) (404)".to_string()));
+        assert!(!config.cached_input(2022, 2).exists());
+        stand_in.requests();
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::{Puzzle, Rng};
+
+use crate::args::{GenerateOptions, Selection};
+use crate::run::selected_puzzles;
+
+/// The input of the puzzle made up as the options ask for.
+pub fn generated_input(puzzle: &Puzzle, options: &GenerateOptions) -> Result<String, String> {
+    let generator = puzzle.generator
+        .ok_or(format!("{} day {:02} can't make up its input", puzzle.year, puzzle.day))?;
+    Ok((generator.generate)(&mut Rng::new(options.seed), options.size.unwrap_or(generator.size)))
+}
+
+/// Writes a made up input of the selected day to stdout, returns false if there is none.
+pub fn generate(selection: &Selection, options: &GenerateOptions) -> bool {
+    let input = selected_puzzles(selection)
+        .and_then(|puzzles| generated_input(&puzzles[0], options));
+    match input {
+        Ok(input) => {
+            print!("{input}");
+            true
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{generate, Generator};
+    use aoc2022_day16::Day16;
+
+    use crate::registry;
+
+    use super::*;
+
+    #[test]
+    fn makes_up_inputs() {
+        let puzzle = registry::find(2022, 16).unwrap();
+        let options = GenerateOptions { size: Some(20), seed: 3 };
+        assert_eq!(generated_input(&puzzle, &options), Ok(generate::generate::<Day16>(3, 20)));
+        assert_eq!(generated_input(&puzzle, &GenerateOptions::default()), Ok(generate::generate::<Day16>(1, Day16::SIZE)));
+
+        let puzzle = Puzzle { generator: None, ..puzzle };
+        assert_eq!(generated_input(&puzzle, &options), Err("2022 day 16 can't make up its input".to_string()));
+    }
+}
@@ -0,0 +1,61 @@
+use std::env;
+use std::process;
+
+use crate::args::Command;
+
+mod args;
+mod bench;
+mod compare;
+mod config;
+mod fetch;
+mod generate;
+mod registry;
+mod run;
+mod scaffold;
+#[cfg(test)]
+mod stand_in;
+mod stress;
+mod submit;
+mod visualize;
+
+fn main() {
+    let command = args::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}\n\n{}", args::USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Help => println!("{}", args::USAGE),
+        Command::Run(selection, options) => if !run::run(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Bench(selection, options) => if !bench::bench(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Fetch(selection, options) => if !fetch::fetch(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Submit(selection, options) => if !submit::submit(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Stress(selection, options) => if !stress::stress(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Generate(selection, options) => if !generate::generate(&selection, &options) {
+            process::exit(1);
+        },
+        Command::Compare(selection, options) => if !compare::compare(&selection, &options) {
+            process::exit(1);
+        },
+        Command::New(selection) => if !scaffold::scaffold(&selection) {
+            process::exit(1);
+        },
+        Command::Session(session) => {
+            let stored = config::Config::from_env().and_then(|config| config.save_session(&session));
+            if let Err(err) = stored {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+        }
+    }
+}
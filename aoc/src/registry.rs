@@ -0,0 +1,53 @@
+use aoc_common::Puzzle;
+
+/// All solved puzzles, ordered by year and day.
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::streaming::<aoc2022_day01::Day01>(2022, 1),
+        Puzzle::streaming::<aoc2022_day02::Day02>(2022, 2),
+        Puzzle::streaming::<aoc2022_day03::Day03>(2022, 3),
+        Puzzle::streaming::<aoc2022_day04::Day04>(2022, 4),
+        Puzzle::generating::<aoc2022_day05::Day05>(2022, 5),
+        Puzzle::streaming::<aoc2022_day06::Day06>(2022, 6),
+        Puzzle::generating::<aoc2022_day07::Day07>(2022, 7),
+        Puzzle::generating::<aoc2022_day08::Day08>(2022, 8),
+        Puzzle::generating::<aoc2022_day09::Day09>(2022, 9),
+        Puzzle::generating::<aoc2022_day10::Day10>(2022, 10),
+        Puzzle::generating::<aoc2022_day11::Day11>(2022, 11),
+        Puzzle::generating::<aoc2022_day12::Day12>(2022, 12),
+        Puzzle::generating::<aoc2022_day13::Day13>(2022, 13),
+        Puzzle::generating::<aoc2022_day14::Day14>(2022, 14),
+        Puzzle::comparing::<aoc2022_day15::Day15>(2022, 15),
+        Puzzle::generating::<aoc2022_day16::Day16>(2022, 16),
+        Puzzle::comparing::<aoc2022_day17::Day17>(2022, 17),
+        Puzzle::generating::<aoc2022_day18::Day18>(2022, 18),
+        Puzzle::generating::<aoc2022_day19::Day19>(2022, 19),
+        Puzzle::comparing::<aoc2022_day20::Day20>(2022, 20),
+        Puzzle::comparing::<aoc2022_day21::Day21>(2022, 21),
+        Puzzle::generating::<aoc2022_day22::Day22>(2022, 22),
+        Puzzle::generating::<aoc2022_day23::Day23>(2022, 23),
+        Puzzle::generating::<aoc2022_day24::Day24>(2022, 24),
+        Puzzle::streaming::<aoc2022_day25::Day25>(2022, 25),
+    ]
+}
+
+pub fn find(year: u16, day: u8) -> Option<Puzzle> {
+    puzzles().into_iter().find(|p| p.year == year && p.day == day)
+}
+
+pub fn latest_year() -> u16 {
+    puzzles().iter().map(|p| p.year).max().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzles_are_unique_and_ordered() {
+        let puzzles = puzzles();
+        assert!(puzzles.windows(2).all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert!(find(2022, 16).is_some());
+        assert!(find(2022, 26).is_none());
+    }
+}
@@ -0,0 +1,422 @@
+use std::any::Any;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use aoc_common::parallel;
+use aoc_common::solution::catch_panic;
+use aoc_common::visualize::{self, Output};
+use aoc_common::{memo, readlines, Answer, Answers, Check, Puzzle, Source, Stream};
+
+use crate::args::{Format, RunOptions, Selection, Visualization};
+use crate::config::Config;
+use crate::registry;
+use crate::visualize::{Images, Terminal};
+
+/// Where the input of a puzzle is expected, relative to the root of the repository.
+pub fn default_input(puzzle: &Puzzle) -> PathBuf {
+    PathBuf::from(format!("{}/day{:02}/input", puzzle.year, puzzle.day))
+}
+
+/// The known answers of the default input are kept next to it.
+pub fn default_answers(puzzle: &Puzzle) -> PathBuf {
+    PathBuf::from(format!("{}/day{:02}/answers.toml", puzzle.year, puzzle.day))
+}
+
+/// The inputs given on the command line, else the one within the repo or the fetched one.
+pub fn input_sources(selection: &Selection, puzzle: &Puzzle) -> Vec<Source> {
+    if !selection.inputs.is_empty() {
+        return selection.inputs.clone();
+    }
+    let default = default_input(puzzle);
+    if default.exists() {
+        return vec!(Source::File(default));
+    }
+    let path = Config::from_env().ok()
+        .map(|config| config.cached_input(puzzle.year, puzzle.day))
+        .filter(|cached| cached.exists())
+        .unwrap_or(default);
+    vec!(Source::File(path))
+}
+
+/// How the records name the input, the text given on the command line isn't repeated.
+fn input_name(source: &Source) -> Option<String> {
+    match source {
+        Source::File(path) => Some(path.display().to_string()),
+        Source::Stdin => Some("-".to_string()),
+        Source::Text(_) => None,
+    }
+}
+
+/// Multi-line answers (e.g. rendered letters) start on a new line.
+fn format_answer(answer: &str) -> String {
+    let answer = answer.trim_end_matches('\n');
+    if answer.contains('\n') { format!("\n{answer}") } else { format!(" {answer}") }
+}
+
+/// Runs one part on the parsed input, a panicking solution is turned into an error so that the other days still run.
+pub fn solve(puzzle: &Puzzle, part: u8, input: &dyn Any) -> Result<Answer, String> {
+    catch_panic(|| puzzle.part(part)(input))
+}
+
+/// Runs one part of a day which can stream its input, like [solve].
+pub fn solve_streamed(puzzle: &Puzzle, part: u8, input: &mut Stream) -> Result<Answer, String> {
+    let streamed = puzzle.streamed.expect("The day streams its input");
+    catch_panic(|| streamed.part(part)(input))?.map_err(|err| err.to_string())
+}
+
+/// The known answers to check against, none for inputs given on the command line.
+fn load_answers(selection: &Selection, puzzle: &Puzzle) -> Result<Option<Answers>, String> {
+    if !selection.inputs.is_empty() {
+        return Ok(None);
+    }
+    let path = default_answers(puzzle);
+    match readlines(&path) {
+        Ok(rows) => Answers::parse(&rows)
+            .map(Some)
+            .map_err(|err| format!("Unable to parse \"{}\": {}", path.display(), err.diagnostic(&rows))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Some(Answers::default())),
+        Err(err) => Err(format!("Unable to read \"{}\": {err}", path.display())),
+    }
+}
+
+/// How the answer compares to the known one, shown behind the part.
+fn format_check(check: Option<&Check>) -> &'static str {
+    match check {
+        None => "",
+        Some(Check::Pass) => " (pass)",
+        Some(Check::Fail { .. }) => " (fail)",
+        Some(Check::Unknown) => " (unknown)",
+    }
+}
+
+pub fn selected_puzzles(selection: &Selection) -> Result<Vec<Puzzle>, String> {
+    if selection.all {
+        return Ok(registry::puzzles().into_iter()
+            .filter(|p| selection.year.is_none_or(|year| year == p.year))
+            .collect());
+    }
+
+    let year = selection.year.unwrap_or_else(registry::latest_year);
+    let day = selection.day.expect("Either a day or all days are selected");
+    registry::find(year, day)
+        .map(|puzzle| vec!(puzzle))
+        .ok_or(format!("There is no solution for {year} day {day}"))
+}
+
+/// The images of each part go into a directory of their own.
+fn visualization_output(visualization: &Visualization, puzzle: &Puzzle, part: u8) -> io::Result<Box<dyn Output>> {
+    Ok(match visualization {
+        Visualization::Ansi => Box::new(Terminal::new()),
+        Visualization::Images(dir) => {
+            let dir = dir.join(format!("{}-day{:02}-part{part}", puzzle.year, puzzle.day));
+            Box::new(Images::new(dir)?)
+        }
+    })
+}
+
+pub fn selected_parts(selection: &Selection) -> Vec<u8> {
+    selection.part.map_or(vec!(1, 2), |part| vec!(part))
+}
+
+/// The outcome of a part, as written by `--format json`.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// None for the text given on the command line.
+    pub input: Option<String>,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: &'static str,
+}
+
+/// How the answer compares to the known one, or why there is none.
+fn status(puzzle: &Puzzle, part: u8, answer: &Result<Answer, String>, check: Option<&Check>) -> &'static str {
+    match (answer, check) {
+        _ if puzzle.unsolved.contains(&part) => "unsolved",
+        (Err(_), _) => "error",
+        (Ok(_), Some(Check::Pass)) => "pass",
+        (Ok(_), Some(Check::Fail { .. })) => "fail",
+        (Ok(Answer::Glyphs(_)), _) => "manual",
+        (Ok(_), _) if puzzle.manual.contains(&part) => "manual",
+        (Ok(_), Some(Check::Unknown)) => "unknown",
+        (Ok(_), None) => "solved",
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+pub fn format_json(records: &[Record]) -> String {
+    let records = records.iter()
+        .map(|r| format!("  {{\"year\": {}, \"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \"type\": {}, \"duration_ns\": {}, \"status\": \"{}\"}}",
+                         r.year, r.day, r.part,
+                         r.input.as_deref().map_or("null".to_string(), json_string),
+                         r.answer.as_ref().map_or("null".to_string(), |answer| json_string(&answer.to_string())),
+                         r.answer.as_ref().map_or("null".to_string(), |answer| format!("\"{}\"", answer.kind())),
+                         r.duration.as_nanos(), r.status))
+        .collect::<Vec<_>>();
+    if records.is_empty() { "[]\n".to_string() } else { format!("[\n{}\n]\n", records.join(",\n")) }
+}
+
+/// Prints the answers of the selected puzzles, returns false if anything went wrong.
+///
+/// With `--format json` the answers are printed as records at the end, and everything else goes to
+/// stderr so that stdout stays machine-readable.
+pub fn run(selection: &Selection, options: &RunOptions) -> bool {
+    let puzzles = match selected_puzzles(selection) {
+        Ok(puzzles) => puzzles,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    if let Some(threads) = options.threads {
+        parallel::set_threads(threads);
+    }
+    let json = options.format == Format::Json;
+    let mut records = vec!();
+    let mut success = true;
+
+    for puzzle in &puzzles {
+        let note = |text: String| if json {
+            eprintln!("{} day {:02}: {text}", puzzle.year, puzzle.day);
+        } else {
+            println!("{text}");
+        };
+        if !json {
+            println!("{} day {:02}", puzzle.year, puzzle.day);
+        }
+
+        let sources = input_sources(selection, puzzle);
+        for source in &sources {
+            if sources.len() > 1 && !json {
+                println!("Input {source}");
+            }
+            // a streamed input is read by each part instead
+            let input = if options.stream {
+                if puzzle.streamed.is_none() {
+                    note("Skipped, the day can't stream its input".to_string());
+                    success &= selection.all;
+                    continue;
+                }
+                None
+            } else {
+                let rows = match source.read() {
+                    Ok(rows) => rows,
+                    Err(err) => {
+                        // a missing input only matters if the day was asked for explicitly
+                        note(format!("Skipped, unable to read {source}: {err}"));
+                        success &= selection.all;
+                        continue;
+                    }
+                };
+
+                match (puzzle.parse)(&rows) {
+                    Ok(input) => Some(input),
+                    Err(err) => {
+                        note(format!("Unable to parse {source}: {}", err.diagnostic(&rows)));
+                        success = false;
+                        continue;
+                    }
+                }
+            };
+
+            let answers = load_answers(selection, puzzle).unwrap_or_else(|err| {
+                note(err);
+                success = false;
+                None
+            });
+
+            for part in selected_parts(selection) {
+                if puzzle.unsolved.contains(&part) {
+                    note(format!("Part {part}: unsolved"));
+                    records.push(Record {
+                        year: puzzle.year,
+                        day: puzzle.day,
+                        part,
+                        input: input_name(source),
+                        answer: None,
+                        duration: Duration::ZERO,
+                        status: "unsolved",
+                    });
+                    continue;
+                }
+                // only the memos of this part, parsing doesn't use any
+                memo::take_stats();
+                if let Some(visualization) = &options.visualize {
+                    match visualization_output(visualization, puzzle, part) {
+                        Ok(output) => visualize::start(output),
+                        Err(err) => {
+                            note(format!("Unable to visualize part {part}: {err}"));
+                            success = false;
+                        }
+                    }
+                }
+
+                let start = Instant::now();
+                let answer = match &input {
+                    Some(input) => solve(puzzle, part, &**input),
+                    None => Stream::open(source)
+                        .map_err(|err| format!("unable to read {source}: {err}"))
+                        .and_then(|mut stream| solve_streamed(puzzle, part, &mut stream)),
+                };
+                let duration = start.elapsed();
+                let check = answer.as_ref().ok()
+                    .and_then(|answer| answers.as_ref().map(|answers| answers.check(part, &answer.to_string())));
+                match &answer {
+                    Ok(answer) if !json => {
+                        println!("Part {part}{}:{}", format_check(check.as_ref()), format_answer(&answer.to_string()));
+                        if let Some(Check::Fail { expected }) = &check {
+                            println!("Expected:{}", format_answer(expected));
+                        }
+                    }
+                    Ok(_) => (),
+                    Err(reason) => note(format!("Part {part} failed: {reason}")),
+                }
+                success &= answer.is_ok() && !matches!(check, Some(Check::Fail { .. }));
+                records.push(Record {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part,
+                    input: input_name(source),
+                    status: status(puzzle, part, &answer, check.as_ref()),
+                    answer: answer.ok(),
+                    duration,
+                });
+
+                if let Err(err) = visualize::finish() {
+                    note(format!("Unable to visualize part {part}: {err}"));
+                    success = false;
+                }
+                if options.verbose {
+                    for stats in memo::take_stats() {
+                        note(format!("  memo {stats}"));
+                    }
+                }
+            }
+        }
+    }
+
+    if json {
+        print!("{}", format_json(&records));
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Grid;
+
+    use super::*;
+
+    #[test]
+    fn solves_the_example() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let input = (puzzle.parse)(&readlines("../2022/day01/examples/test.in").unwrap()).unwrap();
+        assert_eq!(solve(&puzzle, 1, &*input), Ok(Answer::Integer(24000)));
+        assert_eq!(solve(&puzzle, 2, &*input), Ok(Answer::Integer(45000)));
+    }
+
+    #[test]
+    fn panics_are_errors() {
+        let puzzle = Puzzle { part2: |_| panic!("index out of bounds"), ..registry::find(2022, 1).unwrap() };
+        let input = (puzzle.parse)(&readlines("../2022/day01/examples/test.in").unwrap()).unwrap();
+        assert_eq!(solve(&puzzle, 2, &*input), Err("index out of bounds".to_string()));
+    }
+
+    #[test]
+    fn streamed_parts_read_the_input_themselves() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let mut input = Stream::open(&Source::File(PathBuf::from("../2022/day01/examples/test.in"))).unwrap();
+        assert_eq!(solve_streamed(&puzzle, 2, &mut input), Ok(Answer::Integer(45000)));
+        let mut input = Stream::open(&Source::Text("1000\nx\n".to_string())).unwrap();
+        assert_eq!(solve_streamed(&puzzle, 1, &mut input), Err("line 2, column 1: expected i32, found \"x\"\n  x\n  ^".to_string()));
+        assert!(registry::find(2022, 5).unwrap().streamed.is_none());
+    }
+
+    #[test]
+    fn parse_errors_point_to_the_input() {
+        let puzzle = registry::find(2022, 5).unwrap();
+        let rows = ["    [D]", "[N] [C]", " 1   2", "", "move 1 from 2 to x"].map(String::from).to_vec();
+        let err = (puzzle.parse)(&rows).err().unwrap();
+        assert_eq!(err.diagnostic(&rows), concat!(
+            "line 5, column 18: expected usize, found \"x\"\n",
+            "  move 1 from 2 to x\n",
+            "                   ^"));
+    }
+
+    #[test]
+    fn answers_are_checked_for_the_default_input_only() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let given = Selection { day: Some(1), inputs: vec!(Source::Stdin), ..Selection::default() };
+        assert_eq!(load_answers(&given, &puzzle), Ok(None));
+        // the tests run within aoc/, so there are no answers to find
+        let default = Selection { day: Some(1), ..Selection::default() };
+        assert_eq!(load_answers(&default, &puzzle), Ok(Some(Answers::default())));
+    }
+
+    #[test]
+    fn records_the_status_of_parts() {
+        let puzzle = registry::find(2022, 22).unwrap();
+        let solved = Ok(Answer::Integer(42));
+        assert_eq!(status(&puzzle, 1, &solved, Some(&Check::Pass)), "pass");
+        assert_eq!(status(&puzzle, 1, &solved, Some(&Check::Unknown)), "unknown");
+        assert_eq!(status(&puzzle, 1, &solved, None), "solved");
+        assert_eq!(status(&puzzle, 2, &solved, None), "manual");
+        assert_eq!(status(&puzzle, 2, &solved, Some(&Check::Fail { expected: "7".to_string() })), "fail");
+        let unsolved = registry::find(2022, 25).unwrap();
+        assert_eq!(status(&unsolved, 2, &Err("index out of bounds".to_string()), None), "unsolved");
+        assert_eq!(status(&unsolved, 1, &Err("index out of bounds".to_string()), None), "error");
+        assert_eq!(status(&puzzle, 2, &Err("index out of bounds".to_string()), None), "error");
+        let glyphs = Ok(Answer::Glyphs(Grid::new(2, 1, true)));
+        assert_eq!(status(&puzzle, 1, &glyphs, Some(&Check::Unknown)), "manual");
+
+        let record = |part, answer, status| Record {
+            year: 2022, day: 10, part, input: Some("2022/day10/input".to_string()), answer, duration: Duration::from_micros(5), status,
+        };
+        let glyphs = Grid::from_rows(vec!(vec!(true, false), vec!(false, true)));
+        let records = [
+            record(1, Some(Answer::Integer(13140)), "pass"),
+            record(2, Some(Answer::Text("say \"hi\"".to_string())), "solved"),
+            record(2, Some(Answer::Glyphs(glyphs)), "manual"),
+            record(2, None, "error"),
+        ];
+        assert_eq!(format_json(&records), concat!(
+            "[\n",
+            "  {\"year\": 2022, \"day\": 10, \"part\": 1, \"input\": \"2022/day10/input\", \"answer\": \"13140\", \"type\": \"integer\", \"duration_ns\": 5000, \"status\": \"pass\"},\n",
+            "  {\"year\": 2022, \"day\": 10, \"part\": 2, \"input\": \"2022/day10/input\", \"answer\": \"say \\\"hi\\\"\", \"type\": \"string\", \"duration_ns\": 5000, \"status\": \"solved\"},\n",
+            "  {\"year\": 2022, \"day\": 10, \"part\": 2, \"input\": \"2022/day10/input\", \"answer\": \"# \\n #\", \"type\": \"glyphs\", \"duration_ns\": 5000, \"status\": \"manual\"},\n",
+            "  {\"year\": 2022, \"day\": 10, \"part\": 2, \"input\": \"2022/day10/input\", \"answer\": null, \"type\": null, \"duration_ns\": 5000, \"status\": \"error\"}\n",
+            "]\n"));
+        assert_eq!(format_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn selects_puzzles_and_parts() {
+        let all = Selection { all: true, ..Selection::default() };
+        assert_eq!(selected_puzzles(&all).unwrap().len(), registry::puzzles().len());
+        assert_eq!(selected_parts(&all), vec!(1, 2));
+
+        let single = Selection { year: Some(2022), day: Some(16), part: Some(2), ..Selection::default() };
+        let puzzles = selected_puzzles(&single).unwrap();
+        assert_eq!((puzzles[0].year, puzzles[0].day), (2022, 16));
+        assert_eq!(selected_parts(&single), vec!(2));
+
+        assert!(selected_puzzles(&Selection { year: Some(2015), day: Some(1), ..Selection::default() }).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! Sets up the crate of a new day from a template and registers it in the workspace and the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::args::Selection;
+use crate::registry;
+
+const MANIFEST: &str = r#"[package]
+name = "aocYEAR-dayDD"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+
+[lints]
+workspace = true
+"#;
+
+// compiled by the tests as it is, to check the examples of a new day with it
+const SOLUTION: &str = include_str!("scaffold/template.rs");
+
+const TESTS: &str = r#"
+#[cfg(test)]
+mod tests {
+    use aoc_common::check_examples;
+
+    use super::*;
+
+    #[test]
+    fn examples() {
+        check_examples::<DayDD>("examples");
+    }
+}
+"#;
+
+const EXPECTED: &str = "\
+# the answers of the example in test.in, checked once the part isn't unsolved any more, e.g.
+# part1 = 42
+";
+
+/// The number within the line after the prefix, e.g. the year of `"2022/day*",`.
+fn number_after<T: std::str::FromStr>(line: &str, prefix: &str) -> Option<T> {
+    let rest = &line[line.find(prefix)? + prefix.len()..];
+    let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// Inserts the line into the list of lines with a key, in the order of the keys.
+fn insert_sorted<K: Ord>(text: &str, line: &str, key: impl Fn(&str) -> Option<K>) -> Result<String, String> {
+    let new_key = key(line).expect("The inserted line has a key");
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let keyed = lines.iter().enumerate()
+        .filter_map(|(idx, line)| Some((idx, key(line)?)))
+        .collect::<Vec<_>>();
+    if keyed.iter().any(|(_, k)| *k == new_key) {
+        return Err(format!("{} is there already", line.trim()));
+    }
+    let idx = match keyed.iter().find(|(_, k)| *k > new_key) {
+        Some(&(idx, _)) => idx,
+        None => keyed.last().ok_or("there is nothing like it to put it next to")?.0 + 1,
+    };
+    lines.insert(idx, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Reads a file of the repo and applies the edit, returns it with the new content unless that
+/// didn't change anything.
+fn edit(path: PathBuf, apply: impl FnOnce(&str) -> Result<String, String>) -> Result<Option<(PathBuf, String)>, String> {
+    let text = fs::read_to_string(&path).map_err(|err| format!("Unable to read \"{}\": {err}", path.display()))?;
+    let edited = apply(&text).map_err(|err| format!("Unable to register the day in \"{}\": {err}", path.display()))?;
+    Ok((edited != text).then_some((path, edited)))
+}
+
+/// Creates the crate of the day within the repo at `root` and registers it, returns the files
+/// created and changed.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(format!("{year}/day{day:02}"));
+    if dir.exists() {
+        return Err(format!("\"{}\" exists already", dir.display()));
+    }
+
+    // everything is checked before writing anything, so that a failure doesn't leave half a day behind
+    let mut edits = vec!();
+    edits.extend(edit(root.join("Cargo.toml"), |text| {
+        let member = format!("    \"{year}/day*\",");
+        // the year may have days already
+        if text.contains(&member) {
+            return Ok(text.to_string());
+        }
+        insert_sorted(text, &member, |line| number_after::<u16>(line, "    \"").filter(|_| line.ends_with("/day*\",")))
+    })?);
+    edits.extend(edit(root.join("aoc/Cargo.toml"), |text| {
+        let dependency = format!("aoc{year}-day{day:02} = {{ path = \"../{year}/day{day:02}\" }}");
+        insert_sorted(text, &dependency, |line| {
+            number_after::<u16>(line, "aoc").and_then(|_| line.split(' ').next()).map(String::from)
+        })
+    })?);
+    edits.extend(edit(root.join("aoc/src/registry.rs"), |text| {
+        let puzzle = format!("        Puzzle::new::<aoc{year}_day{day:02}::Day{day:02}>({year}, {day}),");
+        insert_sorted(text, &puzzle, |line| {
+            // the days are registered with any of the constructors, e.g. Puzzle::new, Puzzle::streaming,
+            // Puzzle::generating or Puzzle::comparing, as Puzzle::<constructor>::<Day>(year, day)
+            let (_, args) = line.trim().strip_prefix("Puzzle::")?.split_once("::<")?.1.split_once(">(")?;
+            let (year, day) = args.split_once(", ")?;
+            Some((year.parse::<u16>().ok()?, number_after::<u8>(day, "")?))
+        })
+    })?);
+
+    let dd = format!("{day:02}");
+    let fill = |template: &str| template.replace("YEAR", &year.to_string()).replace("DD", &dd);
+    let files = [
+        (dir.join("Cargo.toml"), fill(MANIFEST)),
+        (dir.join("src/lib.rs"), fill(SOLUTION) + &fill(TESTS)),
+        (dir.join("examples/test.in"), String::new()),
+        (dir.join("examples/test.expected"), EXPECTED.to_string()),
+    ];
+    let mut changed = vec!();
+    for (path, content) in files.into_iter().chain(edits) {
+        let write = || -> std::io::Result<()> {
+            fs::create_dir_all(path.parent().expect("The files are within the repo"))?;
+            fs::write(&path, content)
+        };
+        write().map_err(|err| format!("Unable to write \"{}\": {err}", path.display()))?;
+        changed.push(path);
+    }
+    Ok(changed)
+}
+
+/// Creates the selected day within the repo the runner is started in, returns false if that failed.
+pub fn scaffold(selection: &Selection) -> bool {
+    let year = selection.year.unwrap_or_else(registry::latest_year);
+    let day = selection.day.expect("A day is selected");
+    if !Path::new("aoc/src/registry.rs").exists() {
+        eprintln!("aoc new has to be run from the root of the repository");
+        return false;
+    }
+    match new_day(Path::new(""), year, day) {
+        Ok(changed) => {
+            println!("{year} day {day:02}: created, fill in the example and solve it");
+            changed.iter().for_each(|path| println!("  {}", path.display()));
+            true
+        }
+        Err(err) => {
+            eprintln!("{year} day {day:02}: {err}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use aoc_common::check_examples;
+
+    use super::*;
+
+    mod template {
+        include!("scaffold/template.rs");
+    }
+
+    #[test]
+    fn creates_and_registers_days() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/registry.rs"] {
+            fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            fs::copy(Path::new("..").join(file), root.join(file)).unwrap();
+        }
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+
+        let changed = new_day(&root, 2023, 7).unwrap();
+        assert_eq!(changed.len(), 7);
+        assert!(read("Cargo.toml").contains("    \"2022/day*\",\n    \"2023/day*\",\n]"));
+        assert!(read("aoc/Cargo.toml").contains(
+            "aoc2022-day25 = { path = \"../2022/day25\" }\naoc2023-day07 = { path = \"../2023/day07\" }\n"));
+        assert!(read("aoc/src/registry.rs").contains(
+            "(2022, 25),\n        Puzzle::new::<aoc2023_day07::Day07>(2023, 7),\n    ]"));
+        assert!(read("2023/day07/Cargo.toml").contains("name = \"aoc2023-day07\""));
+        assert!(read("2023/day07/src/lib.rs").contains("check_examples::<Day07>"));
+        assert_eq!(read("2023/day07/examples/test.in"), "");
+        // the new day passes its tests until there is something to check
+        check_examples::<template::DayDD>(root.join("2023/day07/examples"));
+
+        // an earlier day goes before the later one, the workspace has the year already
+        assert_eq!(new_day(&root, 2023, 1).unwrap().len(), 6);
+        assert!(read("aoc/src/registry.rs").contains(
+            "Puzzle::new::<aoc2023_day01::Day01>(2023, 1),\n        Puzzle::new::<aoc2023_day07::Day07>(2023, 7),"));
+        assert!(new_day(&root, 2023, 7).unwrap_err().contains("exists already"));
+        // nothing gets written if the day can't be registered
+        fs::remove_dir_all(root.join("2023/day07")).unwrap();
+        assert!(new_day(&root, 2023, 7).unwrap_err().contains("is there already"));
+        assert!(!root.join("2023/day07").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
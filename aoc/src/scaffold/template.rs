@@ -0,0 +1,36 @@
+use aoc_common::{lines, ParseError, Solution};
+
+fn parse(rows: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    Ok(lines(rows).map(|line| line.text.to_string()).collect())
+}
+
+// unsolved, the answer isn't used until the part is taken out of UNSOLVED_PARTS
+fn part1(_: &Vec<String>) -> usize {
+    0
+}
+
+fn part2(_: &Vec<String>) -> usize {
+    0
+}
+
+pub struct DayDD;
+
+impl Solution for DayDD {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const UNSOLVED_PARTS: &'static [u8] = &[1, 2];
+
+    fn parse(rows: &Vec<String>) -> Result<Vec<String>, ParseError> {
+        parse(rows)
+    }
+
+    fn part1(input: &Vec<String>) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<String>) -> usize {
+        part2(input)
+    }
+}
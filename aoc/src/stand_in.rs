@@ -0,0 +1,57 @@
+//! A local HTTP server standing in for the Advent of Code website in the tests.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+pub struct StandIn {
+    pub base_url: String,
+    server: JoinHandle<Vec<String>>,
+}
+
+impl StandIn {
+    /// Answers one request after the other with the given status and body.
+    pub fn serve(responses: &[(u16, &str)]) -> StandIn {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<(u16, String)> = responses.iter().map(|&(status, body)| (status, body.to_string())).collect();
+
+        let server = thread::spawn(move || {
+            let mut requests = vec!();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request += &line;
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                requests.push(request);
+
+                write!(stream, "HTTP/1.1 {status} Stand-In\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            }
+            requests
+        });
+
+        StandIn { base_url, server }
+    }
+
+    /// The requests received, each with its head and body.
+    pub fn requests(self) -> Vec<String> {
+        self.server.join().unwrap()
+    }
+}
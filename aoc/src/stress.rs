@@ -0,0 +1,101 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::stream::Generated;
+use aoc_common::{Answer, Puzzle, Stream};
+
+use crate::args::{Selection, StressOptions};
+use crate::run::{selected_parts, selected_puzzles, solve_streamed};
+
+/// Solves the part while making up its input, the duration includes generating it.
+pub fn stress_part(puzzle: &Puzzle, part: u8, options: &StressOptions) -> (Result<Answer, String>, Duration) {
+    let streamed = puzzle.streamed.expect("The day streams its input");
+    let generated = Generated::with(options.seed, options.size, streamed.generate, streamed.generate_end);
+    let start = Instant::now();
+    let answer = solve_streamed(puzzle, part, &mut Stream::new(generated));
+    (answer, start.elapsed())
+}
+
+fn mebibytes(bytes: u64) -> f64 {
+    bytes as f64 / (1 << 20) as f64
+}
+
+/// How much was solved how fast, e.g. `1024.0 MiB in 2.345s, 436.7 MiB/s`.
+fn format_throughput(bytes: u64, duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    format!("{:.1} MiB in {secs:.3}s, {:.1} MiB/s", mebibytes(bytes), mebibytes(bytes) / secs.max(f64::MIN_POSITIVE))
+}
+
+/// Feeds made up inputs to the selected days which can stream them, returns false if anything went wrong.
+pub fn stress(selection: &Selection, options: &StressOptions) -> bool {
+    let puzzles = match selected_puzzles(selection) {
+        Ok(puzzles) => puzzles,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    let mut success = true;
+
+    for puzzle in &puzzles {
+        if puzzle.streamed.is_none() {
+            // only matters if the day was asked for explicitly
+            if !selection.all {
+                eprintln!("{} day {:02} can't stream its input", puzzle.year, puzzle.day);
+                success = false;
+            }
+            continue;
+        }
+        println!("{} day {:02}", puzzle.year, puzzle.day);
+
+        for part in selected_parts(selection) {
+            if puzzle.unsolved.contains(&part) {
+                println!("Part {part}: unsolved");
+                continue;
+            }
+            match stress_part(puzzle, part, options) {
+                (Ok(answer), duration) => println!("Part {part}: {answer} ({})", format_throughput(options.size, duration)),
+                (Err(reason), _) => {
+                    println!("Part {part} failed: {reason}");
+                    success = false;
+                }
+            }
+        }
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use aoc_common::Solution;
+    use aoc2022_day01::Day01;
+
+    use crate::registry;
+
+    use super::*;
+
+    #[test]
+    fn solves_made_up_inputs() {
+        let puzzle = registry::find(2022, 1).unwrap();
+        let options = StressOptions { size: 1 << 16, seed: 5 };
+        let (answer, _) = stress_part(&puzzle, 2, &options);
+
+        // the same input parsed as a whole
+        let mut text = String::new();
+        Generated::new::<Day01>(5, 1 << 16).read_to_string(&mut text).unwrap();
+        let rows = text.lines().map(String::from).collect();
+        assert_eq!(answer, Ok(Day01::part2(&Day01::parse(&rows).unwrap()).into()));
+        assert_eq!(stress_part(&puzzle, 2, &options).0, answer);
+        assert_ne!(stress_part(&puzzle, 2, &StressOptions { seed: 6, ..options }).0, answer);
+
+        let puzzle = registry::find(2022, 25).unwrap();
+        assert_eq!(stress_part(&puzzle, 2, &options).0, Err("there is no part 2".to_string()));
+    }
+
+    #[test]
+    fn formats_the_throughput() {
+        assert_eq!(format_throughput(3 << 20, Duration::from_millis(1500)), "3.0 MiB in 1.500s, 2.0 MiB/s");
+    }
+}
@@ -0,0 +1,327 @@
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::readlines;
+
+use crate::args::{Selection, SubmitOptions};
+use crate::config::Config;
+use crate::fetch::{agent, request_error};
+use crate::registry;
+use crate::run::{input_sources, solve};
+
+/// How the website responded to a guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the answer is known to be wrong, i.e. submitting it again is pointless.
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    /// Recognizes the response by the sentences the website uses.
+    pub fn classify(response: &str) -> Outcome {
+        if response.contains("That's the right answer") {
+            Outcome::Correct
+        } else if response.contains("You gave an answer too recently") {
+            Outcome::RateLimited
+        } else if response.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if response.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if response.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else {
+            Outcome::Unknown
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited => "rate-limited",
+            Outcome::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            "rate-limited" => Ok(Outcome::RateLimited),
+            "unknown" => Ok(Outcome::Unknown),
+            other => Err(format!("Unknown outcome {other}")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// seconds since the epoch
+    pub time: u64,
+}
+
+impl Guess {
+    fn parse(row: &str) -> Option<Guess> {
+        match row.split('\t').collect::<Vec<_>>().as_slice() {
+            [part, answer, outcome, time] => Some(Guess {
+                part: part.parse().ok()?,
+                answer: answer.to_string(),
+                outcome: outcome.parse().ok()?,
+                time: time.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// All guesses for a day, kept as tab separated lines of part, answer, outcome and time.
+#[derive(Debug, PartialEq)]
+pub struct History {
+    pub path: PathBuf,
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<History, String> {
+        let rows = match readlines(path) {
+            Ok(rows) => rows,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec!(),
+            Err(err) => return Err(format!("Unable to read \"{}\": {err}", path.display())),
+        };
+        let guesses = rows.iter().enumerate()
+            .map(|(idx, row)| Guess::parse(row)
+                .ok_or(format!("Malformed guess in line {} of \"{}\"", idx + 1, path.display())))
+            .collect::<Result<_, _>>()?;
+        Ok(History { path: path.to_path_buf(), guesses })
+    }
+
+    pub fn record(&mut self, guess: Guess) -> Result<(), String> {
+        let append = || -> io::Result<()> {
+            fs::create_dir_all(self.path.parent().expect("The history is within a directory"))?;
+            let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+            writeln!(file, "{}\t{}\t{}\t{}", guess.part, guess.answer, guess.outcome, guess.time)
+        };
+        append().map_err(|err| format!("Unable to record the guess in \"{}\": {err}", self.path.display()))?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Why the answer mustn't be submitted given the earlier guesses, if so.
+    pub fn refusal(&self, part: u8, answer: &str) -> Option<String> {
+        let guesses = self.guesses.iter().filter(|g| g.part == part).collect::<Vec<_>>();
+
+        if let Some(correct) = guesses.iter().find(|g| g.outcome == Outcome::Correct) {
+            return Some(format!("part {part} is solved already with {}", correct.answer));
+        }
+        if let Some(wrong) = guesses.iter().find(|g| g.answer == answer && g.outcome.is_wrong()) {
+            return Some(format!("{answer} was guessed already and is {}", wrong.outcome));
+        }
+
+        let number = answer.parse::<i128>().ok()?;
+        let numbers = |outcome| guesses.iter()
+            .filter(move |g| g.outcome == outcome)
+            .filter_map(|g| g.answer.parse::<i128>().ok());
+        if let Some(too_high) = numbers(Outcome::TooHigh).min().filter(|&too_high| number >= too_high) {
+            return Some(format!("{answer} is too high, {too_high} was too high already"));
+        }
+        if let Some(too_low) = numbers(Outcome::TooLow).max().filter(|&too_low| number <= too_low) {
+            return Some(format!("{answer} is too low, {too_low} was too low already"));
+        }
+        None
+    }
+}
+
+/// The text of the response's article, which is what the website has to say about the guess.
+fn message(response: &str) -> String {
+    let article = response.split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(response, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Posts the guess, returns the outcome along with the website's message.
+pub fn post(config: &Config, year: u16, day: u8, part: u8, answer: &str) -> Result<(Outcome, String), String> {
+    let session = config.session()?;
+    let url = format!("{}/{year}/day/{day}/answer", config.base_url);
+
+    let response = agent().post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(request_error)?
+        .into_string()
+        .map_err(|err| err.to_string())?;
+    Ok((Outcome::classify(&response), message(&response)))
+}
+
+pub fn history_path(config: &Config, year: u16, day: u8) -> PathBuf {
+    config.cache_dir.join(format!("{year}/day{day:02}/guesses"))
+}
+
+/// Solves the part on the input to get the answer to submit.
+fn compute_answer(selection: &Selection, year: u16, day: u8, part: u8) -> Result<String, String> {
+    let puzzle = registry::find(year, day).ok_or(format!("There is no solution for {year} day {day}"))?;
+    if puzzle.unsolved.contains(&part) {
+        return Err(format!("Part {part} isn't solved yet"));
+    }
+    // the arguments allow a single input only
+    let source = input_sources(selection, &puzzle).remove(0);
+    let rows = source.read().map_err(|err| format!("Unable to read {source}: {err}"))?;
+    let input = (puzzle.parse)(&rows)
+        .map_err(|err| format!("Unable to parse {source}: {}", err.diagnostic(&rows)))?;
+    let answer = solve(&puzzle, part, &*input).map_err(|reason| format!("Part {part} failed: {reason}"))?;
+    answer.submission()
+        .ok_or(format!("The answer has to be read, pass it with --answer:\n{answer}"))
+}
+
+/// Submits the answer unless the history tells it is wrong and records the outcome.
+pub fn submit_answer(config: &Config, year: u16, day: u8, part: u8, answer: &str) -> Result<(Outcome, String), String> {
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(format!("{answer:?} can't be submitted, read the answer and pass it with --answer"));
+    }
+    let mut history = History::load(&history_path(config, year, day))?;
+    if let Some(refusal) = history.refusal(part, answer) {
+        return Err(format!("Not submitted, {refusal}"));
+    }
+
+    let (outcome, message) = post(config, year, day, part, answer)?;
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    history.record(Guess { part, answer: answer.to_string(), outcome, time })?;
+    Ok((outcome, message))
+}
+
+/// Submits the given or computed answer, returns true if it was correct.
+pub fn submit(selection: &Selection, options: &SubmitOptions) -> bool {
+    let year = selection.year.unwrap_or_else(registry::latest_year);
+    let day = selection.day.expect("A day is selected");
+    let part = selection.part.expect("A part is selected");
+
+    let result = Config::from_env().and_then(|config| {
+        let answer = match &options.answer {
+            Some(answer) => answer.clone(),
+            None => compute_answer(selection, year, day, part)?,
+        };
+        println!("{year} day {day:02} part {part}: submitting {answer}");
+        submit_answer(&config, year, day, part, &answer)
+    });
+
+    match result {
+        Ok((outcome, message)) => {
+            println!("{outcome}: {message}");
+            outcome == Outcome::Correct
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::stand_in::StandIn;
+
+    use super::*;
+
+    fn guess(part: u8, answer: &str, outcome: Outcome) -> Guess {
+        Guess { part, answer: answer.to_string(), outcome, time: 0 }
+    }
+
+    #[test]
+    fn classifies_responses() {
+        let wrong = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+                     make sure you're using the full input data.</p></article>\n</main>";
+        assert_eq!(Outcome::classify(wrong), Outcome::TooHigh);
+        assert_eq!(message(wrong), "That's not the right answer; your answer is too high. If you're stuck, \
+                                    make sure you're using the full input data.");
+        assert_eq!(Outcome::classify("That's not the right answer; your answer is too low."), Outcome::TooLow);
+        assert_eq!(Outcome::classify("That's not the right answer."), Outcome::Wrong);
+        assert_eq!(Outcome::classify("That's the right answer! You are one gold star closer."), Outcome::Correct);
+        assert_eq!(Outcome::classify("You gave an answer too recently; you have 38s left to wait."), Outcome::RateLimited);
+        assert_eq!(Outcome::classify("You don't seem to be solving the right level."), Outcome::Unknown);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = History { path: PathBuf::new(), guesses: vec!(
+            guess(1, "500", Outcome::TooHigh),
+            guess(1, "800", Outcome::TooHigh),
+            guess(1, "100", Outcome::TooLow),
+            guess(1, "ABC", Outcome::Wrong),
+            guess(1, "300", Outcome::RateLimited),
+            guess(2, "42", Outcome::Correct),
+        )};
+        assert_eq!(history.refusal(1, "300"), None);
+        assert_eq!(history.refusal(1, "XYZ"), None);
+        assert_eq!(history.refusal(1, "ABC"), Some("ABC was guessed already and is wrong".to_string()));
+        assert_eq!(history.refusal(1, "600"), Some("600 is too high, 500 was too high already".to_string()));
+        assert_eq!(history.refusal(1, "100"), Some("100 was guessed already and is too-low".to_string()));
+        assert_eq!(history.refusal(1, "50"), Some("50 is too low, 100 was too low already".to_string()));
+        assert_eq!(history.refusal(2, "43"), Some("part 2 is solved already with 42".to_string()));
+    }
+
+    #[test]
+    fn submits_and_records_guesses() {
+        let stand_in = StandIn::serve(&[
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let config = Config {
+            base_url: stand_in.base_url.clone(),
+            session: Some("cookie".to_string()),
+            session_file: PathBuf::from("/nonexistent/session"),
+            cache_dir: env::temp_dir().join(format!("aoc-submit-{}", std::process::id())),
+        };
+
+        assert_eq!(submit_answer(&config, 2022, 1, 1, "100").map(|(outcome, _)| outcome), Ok(Outcome::TooLow));
+        assert!(submit_answer(&config, 2022, 1, 1, "90").unwrap_err().contains("too low"));
+        assert!(submit_answer(&config, 2022, 1, 1, "##\n##").is_err());
+        assert_eq!(submit_answer(&config, 2022, 1, 1, "200").map(|(outcome, _)| outcome), Ok(Outcome::Correct));
+
+        let history = History::load(&history_path(&config, 2022, 1)).unwrap();
+        fs::remove_dir_all(&config.cache_dir).unwrap();
+        assert_eq!(history.guesses.iter().map(|g| (g.answer.as_str(), g.outcome)).collect::<Vec<_>>(),
+                   vec!(("100", Outcome::TooLow), ("200", Outcome::Correct)));
+
+        let requests = stand_in.requests();
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"), "{}", requests[0]);
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=100"), "{}", requests[0]);
+    }
+}
@@ -0,0 +1,108 @@
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::visualize::{Color, Frame, Output};
+
+/// The terminal redraws the frames in place with colors, at most this many per second.
+const FRAMES_PER_SECOND: u64 = 30;
+
+/// Each tile becomes a square of this many pixels in the images.
+const SCALE: usize = 4;
+
+pub struct Terminal {
+    last: Option<Instant>,
+}
+
+impl Terminal {
+    pub fn new() -> Terminal {
+        Terminal { last: None }
+    }
+}
+
+impl Output for Terminal {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut out = BufWriter::new(io::stdout().lock());
+        // to the top left and clear everything below
+        write!(out, "\x1b[H\x1b[J")?;
+        for row in frame.rows() {
+            let mut color = None;
+            for pixel in row {
+                if color != Some(pixel.color) {
+                    let Color { r, g, b } = pixel.color;
+                    write!(out, "\x1b[38;2;{r};{g};{b}m")?;
+                    color = Some(pixel.color);
+                }
+                write!(out, "{}", pixel.glyph)?;
+            }
+            writeln!(out, "\x1b[0m")?;
+        }
+        out.flush()?;
+
+        if let Some(last) = self.last {
+            let frame_time = Duration::from_millis(1000 / FRAMES_PER_SECOND);
+            thread::sleep(frame_time.saturating_sub(last.elapsed()));
+        }
+        self.last = Some(Instant::now());
+        Ok(())
+    }
+}
+
+/// Writes each frame as a numbered PPM image, e.g. to turn them into a video or GIF with ffmpeg.
+pub struct Images {
+    dir: PathBuf,
+    count: usize,
+}
+
+impl Images {
+    pub fn new(dir: PathBuf) -> io::Result<Images> {
+        fs::create_dir_all(&dir)?;
+        Ok(Images { dir, count: 0 })
+    }
+}
+
+impl Output for Images {
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        self.count += 1;
+        let path = self.dir.join(format!("frame{:05}.ppm", self.count));
+        let mut out = BufWriter::new(fs::File::create(path)?);
+
+        write!(out, "P6\n{} {}\n255\n", frame.width() * SCALE, frame.height() * SCALE)?;
+        for row in frame.rows() {
+            let line = row.iter()
+                .flat_map(|pixel| [pixel.color.r, pixel.color.g, pixel.color.b].repeat(SCALE))
+                .collect::<Vec<_>>();
+            for _ in 0..SCALE {
+                out.write_all(&line)?;
+            }
+        }
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::visualize::Pixel;
+    use aoc_common::Grid;
+
+    use super::*;
+
+    #[test]
+    fn writes_images() {
+        let dir = std::env::temp_dir().join(format!("aoc-visualize-{}", std::process::id()));
+        let mut images = Images::new(dir.clone()).unwrap();
+        let mut frame = Grid::new(2, 1, Pixel::EMPTY);
+        frame[(0, 1)] = Pixel::new('#', Color::new(1, 2, 3));
+        images.write(&frame).unwrap();
+        images.write(&frame).unwrap();
+
+        let image = fs::read(dir.join("frame00002.ppm")).unwrap();
+        let header = format!("P6\n{} {}\n255\n", 2 * SCALE, SCALE);
+        assert!(image.starts_with(header.as_bytes()));
+        assert_eq!(image.len(), header.len() + 2 * SCALE * SCALE * 3);
+        assert_eq!(image[header.len() + SCALE * 3..header.len() + SCALE * 3 + 3], [1, 2, 3]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}